@@ -0,0 +1,120 @@
+use std::hint::black_box;
+use std::path::Path;
+use std::process;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use aoc_common::input::{self, Source};
+use aoc_common::Solution;
+use criterion::{criterion_group, Criterion};
+
+// what every part should take at most on the puzzle input, as the readme has it
+const BUDGET: Duration = Duration::from_millis(100);
+
+// the parts that went over the budget, reported once every day is done
+static OVER_BUDGET: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn solve<S: Solution>(input: &S::Input, part: u32) -> Option<String> {
+    match part {
+        1 => Some(S::part1(input)),
+        _ => S::part2(input),
+    }
+}
+
+// the fastest of a few runs, which is the least noisy thing to hold to a budget
+fn fastest<T>(mut f: impl FnMut() -> T) -> Duration {
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+// times parsing and every part of a day on its own, on the puzzle input when
+// there is one. the example stands in for a missing one, under a name of its
+// own so the two never get compared
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(input::day_dir(S::DAY));
+
+    let (content, name) = match Source::Puzzle.read(&dir) {
+        Ok(content) => (content, format!("day{}", S::DAY)),
+        Err(err) => {
+            eprintln!(
+                "warning: no {} ({}), benchmarking day {} on its example instead",
+                Source::Puzzle.describe(&dir),
+                err,
+                S::DAY
+            );
+            let content = Source::Example.read(&dir).unwrap();
+            (content, format!("day{}-example", S::DAY))
+        }
+    };
+    let input = S::parse(&content).unwrap();
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&content))));
+    for part in 1..=S::PARTS {
+        let took = fastest(|| solve::<S>(&input, part));
+        if took > BUDGET {
+            let over = format!(
+                "day {} part {}: {:.1} ms",
+                S::DAY,
+                part,
+                took.as_secs_f64() * 1000.0
+            );
+            OVER_BUDGET.lock().unwrap().push(over);
+        }
+
+        let id = format!("part{}", part);
+        group.bench_function(id, |b| b.iter(|| solve::<S>(black_box(&input), part)));
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_day::<day1::Day1>,
+    bench_day::<day2::Day2>,
+    bench_day::<day3::Day3>,
+    bench_day::<day4::Day4>,
+    bench_day::<day5::Day5>,
+    bench_day::<day6::Day6>,
+    bench_day::<day7::Day7>,
+    bench_day::<day8::Day8>,
+    bench_day::<day9::Day9>,
+    bench_day::<day10::Day10>,
+    bench_day::<day11::Day11>,
+    bench_day::<day12::Day12>,
+    bench_day::<day13::Day13>,
+    bench_day::<day14::Day14>,
+    bench_day::<day15::Day15>,
+    bench_day::<day16::Day16>,
+    bench_day::<day17::Day17>,
+    bench_day::<day18::Day18>,
+    bench_day::<day19::Day19>,
+    bench_day::<day20::Day20>,
+    bench_day::<day21::Day21>,
+    bench_day::<day22::Day22>,
+    bench_day::<day23::Day23>,
+    bench_day::<day24::Day24>,
+    bench_day::<day25::Day25>
+);
+
+fn main() {
+    benches();
+    Criterion::default().configure_from_args().final_summary();
+
+    let over = OVER_BUDGET.lock().unwrap();
+    if !over.is_empty() {
+        eprintln!("over the {} ms budget:", BUDGET.as_millis());
+        for part in over.iter() {
+            eprintln!("  {}", part);
+        }
+        process::exit(1);
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+use aoc_common::parse::Scanner;
+use aoc_common::{Answer, ParseError};
+
+// known good answers by day and part, stored in the same format `run --all` prints them:
+//
+//     Day 1
+//     1: 24000
+//     2: 45000
+//
+// with multi line answers starting on the line after their part number
+#[derive(Default)]
+pub struct Answers(BTreeMap<(u32, u32), String>);
+
+impl Answers {
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    // the parts of the day that have an answer recorded
+    pub fn parts(&self, day: u32) -> impl Iterator<Item = u32> + '_ {
+        self.0.range((day, 0)..=(day, u32::MAX)).map(|(&(_, part), _)| part)
+    }
+
+    pub fn insert(&mut self, day: u32, answer: Answer) {
+        self.0.insert((day, answer.part), answer.value);
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+
+        let mut day = None;
+        // the multi line answer that is being read, if any
+        let mut current = None;
+
+        for (i, line) in content.lines().enumerate() {
+            let mut s = Scanner::at_line(line, i + 1);
+
+            if line.is_empty() {
+                (day, current) = (None, None);
+                continue;
+            }
+
+            if s.eat("Day ") {
+                day = Some(s.number_in(1, 25)?);
+                current = None;
+                s.finish()?;
+                continue;
+            }
+
+            let Some(day) = day else {
+                return Err(s.error("`Day `"));
+            };
+
+            if s.peek().is_some_and(|c| c.is_ascii_digit()) {
+                let part = s.number_in(1, 2)?;
+                s.expect(":")?;
+
+                let value = if s.is_done() {
+                    current = Some(part);
+                    String::new()
+                } else {
+                    current = None;
+                    s.expect(" ")?;
+                    s.rest().to_string()
+                };
+                answers.0.insert((day, part), value);
+            } else if let Some(part) = current {
+                let value = answers.0.get_mut(&(day, part)).unwrap();
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+                value.push_str(line);
+            } else {
+                return Err(s.error("a part number"));
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+// editors like to strip trailing spaces, which the day 10 crt is full of
+pub fn same(expected: &str, value: &str) -> bool {
+    expected
+        .lines()
+        .map(str::trim_end)
+        .eq(value.lines().map(str::trim_end))
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut last = None;
+
+        for (&(day, part), value) in &self.0 {
+            if last != Some(day) {
+                if last.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "Day {}", day)?;
+                last = Some(day);
+            }
+
+            let answer = Answer {
+                part,
+                value: value.clone(),
+                elapsed: Duration::ZERO,
+            };
+            writeln!(f, "{}", answer)?;
+        }
+
+        Ok(())
+    }
+}
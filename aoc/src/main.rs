@@ -0,0 +1,437 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_common::input::{self, InputArgs, Source};
+use aoc_common::output::{self, Format};
+use aoc_common::profile::{self, Counting, Phase};
+use aoc_common::render::{Format as Frames, Recorder, RenderArgs};
+use aoc_common::step;
+use aoc_common::Day;
+use clap::{Args, Parser, Subcommand};
+
+use answers::Answers;
+
+mod answers;
+mod days;
+mod summary;
+
+// counts allocations for --profile
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+// where the recorded answers of the puzzle inputs and examples live
+const ANSWERS_DIR: &str = "answers";
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for one day, or for all of them
+    Run(RunArgs),
+    /// Check every day against the recorded answers
+    Verify(VerifyArgs),
+    /// Write a random input for a day
+    Generate(GenerateArgs),
+    /// Show the simulation of a day, in the terminal or as images
+    Render(RenderDayArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25), required_unless_present = "all")]
+    day: Option<u32>,
+
+    /// Only run this part of the day
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2), requires = "day")]
+    part: Option<u32>,
+
+    /// Run every day in sequence
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// Run every day on N threads instead (0 is one per core) and print a summary table
+    #[arg(short, long, value_name = "N", requires = "all", conflicts_with = "profile")]
+    jobs: Option<usize>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Report the time and peak allocation of reading, parsing and every part
+    #[arg(long)]
+    profile: bool,
+
+    /// Step through the simulation of the part (1 by default) with a small debugger instead
+    #[arg(long, requires = "day", conflicts_with = "profile")]
+    step: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only check this day
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: Option<u32>,
+
+    /// Check the puzzle examples instead of the real inputs
+    #[arg(short, long)]
+    example: bool,
+
+    /// Answers file to check against, answers/input or answers/example by default
+    #[arg(long, value_name = "PATH")]
+    answers: Option<PathBuf>,
+
+    /// Record the current answers as the known good ones instead of checking them
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// How big the input gets, in whatever unit suits the day (elves, moves, side of the map...)
+    #[arg(short, long, default_value_t = 100)]
+    size: usize,
+
+    /// Seed for the random generator, the same seed always gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// File to write the input to instead of stdout
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct RenderDayArgs {
+    /// Day to render, one of 9, 14, 17, 23 and 24
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    render: RenderArgs,
+}
+
+// bytes in the largest unit that keeps them above 1
+fn bytes(n: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = n as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < units.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{} B", n),
+        _ => format!("{:.1} {}", size, units[unit]),
+    }
+}
+
+// goes to stderr, so it can be asked for along with --format json
+fn report(day: u32, phases: &[Phase]) {
+    eprintln!("Day {} profile", day);
+    eprintln!("  {:<8} {:>12} {:>12}", "phase", "time", "peak");
+    for phase in phases {
+        eprintln!(
+            "  {:<8} {:>9.3} ms {:>12}",
+            phase.name,
+            phase.elapsed.as_secs_f64() * 1000.0,
+            bytes(phase.peak)
+        );
+    }
+}
+
+fn run_day(day: &dyn Day, source: &Source, args: &RunArgs) -> Result<(), String> {
+    let (part, format) = (args.part, args.format);
+    if let Some(part) = part.filter(|&part| part > day.parts()) {
+        return Err(format!("day {} has no part {}", day.day(), part));
+    }
+
+    let dir = input::day_dir(day.day());
+    let (content, read) = profile::measure("read", || source.read(&dir));
+    let content =
+        content.map_err(|err| format!("could not read {}: {}", source.describe(&dir), err))?;
+
+    let profiled = day
+        .profile(&content, part)
+        .map_err(|err| err.report(&content))?;
+    if format == Format::Text {
+        println!("Day {}", day.day());
+    }
+    output::print(day.day(), &profiled.answers, format);
+
+    if args.profile {
+        let mut phases = vec![read];
+        phases.extend(profiled.phases);
+        report(day.day(), &phases);
+    }
+
+    Ok(())
+}
+
+fn step_day(day: &dyn Day, source: &Source, part: u32) -> Result<(), String> {
+    if *source == Source::Stdin {
+        return Err("the commands come from stdin, so the input has to be a file".to_string());
+    }
+
+    let dir = input::day_dir(day.day());
+    let content = source
+        .read(&dir)
+        .map_err(|err| format!("could not read {}: {}", source.describe(&dir), err))?;
+
+    let mut res = Ok(());
+    let stepped = day
+        .step(&content, part, &mut |sim| {
+            res = step::repl(sim, io::stdin().lock(), io::stdout());
+        })
+        .map_err(|err| err.report(&content))?;
+
+    if !stepped {
+        return Err(format!("day {} has no simulation to step through", day.day()));
+    }
+    res.map_err(|err| err.to_string())
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let source = args.input.source();
+
+    if args.step {
+        let day = days::get(args.day.unwrap()).unwrap();
+        return step_day(day, &source, args.part.unwrap_or(1));
+    }
+
+    if let Some(jobs) = args.jobs {
+        summary::run(jobs, &source, args.format)
+    } else if args.all {
+        for (i, day) in days::DAYS.iter().enumerate() {
+            if i > 0 && args.format == Format::Text {
+                println!();
+            }
+            run_day(*day, &source, &args)?;
+        }
+        Ok(())
+    } else {
+        let day = days::get(args.day.unwrap()).unwrap();
+        run_day(day, &source, &args)
+    }
+}
+
+// describes a multi line answer on lines of its own
+fn show(value: &str) -> String {
+    if value.contains('\n') {
+        format!("\n{}\n", value)
+    } else {
+        value.to_string()
+    }
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let source = if args.example {
+        Source::Example
+    } else {
+        Source::Puzzle
+    };
+
+    let path = args.answers.unwrap_or_else(|| {
+        let name = if args.example {
+            input::EXAMPLE
+        } else {
+            input::INPUT
+        };
+        Path::new(ANSWERS_DIR).join(name)
+    });
+
+    let mut answers = match input::read(&path) {
+        Ok(content) => Answers::parse(&content).map_err(|err| err.report(&content))?,
+        // nothing recorded yet is fine when about to record
+        Err(_) if args.record => Answers::default(),
+        Err(err) => {
+            return Err(format!(
+                "could not read {}: {} (record it with `verify --record`)",
+                path.display(),
+                err
+            ))
+        }
+    };
+
+    let days = match args.day {
+        Some(day) => vec![days::get(day).unwrap()],
+        None => days::DAYS.to_vec(),
+    };
+
+    let (mut checked, mut failed) = (0, 0);
+    for day in days {
+        let dir = input::day_dir(day.day());
+        let Ok(content) = source.read(&dir) else {
+            // answers recorded for it go unchecked, which is no pass either
+            let unchecked = if args.record { 0 } else { answers.parts(day.day()).count() };
+            if unchecked > 0 {
+                println!(
+                    "Day {}: no {}, {} recorded answers unchecked",
+                    day.day(),
+                    source.describe(&dir),
+                    unchecked
+                );
+            } else {
+                println!("Day {}: skipped, no {}", day.day(), source.describe(&dir));
+            }
+            checked += unchecked;
+            failed += unchecked;
+            continue;
+        };
+
+        let solved = match day.solve(&content, None) {
+            Ok(solved) => solved,
+            Err(err) => {
+                println!("Day {}: {}", day.day(), err.report(&content));
+                failed += 1;
+                continue;
+            }
+        };
+
+        // a recorded answer that is no longer produced fails as well
+        if !args.record {
+            for part in answers.parts(day.day()) {
+                if !solved.iter().any(|answer| answer.part == part) {
+                    let expected = answers.get(day.day(), part).unwrap();
+                    println!(
+                        "Day {} part {}: expected {}, got nothing",
+                        day.day(),
+                        part,
+                        show(expected)
+                    );
+                    checked += 1;
+                    failed += 1;
+                }
+            }
+        }
+
+        for answer in solved {
+            if args.record {
+                answers.insert(day.day(), answer);
+                continue;
+            }
+
+            match answers.get(day.day(), answer.part) {
+                // a lost answer must not pass for a good one
+                None => {
+                    println!("Day {} part {}: no recorded answer", day.day(), answer.part);
+                    checked += 1;
+                    failed += 1;
+                }
+                Some(expected) if answers::same(expected, &answer.value) => checked += 1,
+                Some(expected) => {
+                    println!(
+                        "Day {} part {}: expected {}, got {}",
+                        day.day(),
+                        answer.part,
+                        show(expected),
+                        show(&answer.value)
+                    );
+                    checked += 1;
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    if args.record {
+        if failed > 0 {
+            return Err(format!("{} days failed, nothing was recorded", failed));
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+        }
+        fs::write(&path, answers.to_string())
+            .map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+        println!("recorded answers in {}", path.display());
+        return Ok(());
+    }
+
+    println!("{} answers checked, {} failed", checked, failed);
+    if failed > 0 {
+        return Err(format!("answers do not match {}", path.display()));
+    }
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<(), String> {
+    let day = days::get(args.day).unwrap();
+    let input = day.generate(args.size, args.seed);
+
+    match args.output {
+        Some(path) => fs::write(&path, input)
+            .map_err(|err| format!("could not write {}: {}", path.display(), err)),
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn render(args: RenderDayArgs) -> Result<(), String> {
+    let day = days::get(args.day).unwrap();
+    let (source, dir) = (args.input.source(), input::day_dir(day.day()));
+    let content = source
+        .read(&dir)
+        .map_err(|err| format!("could not read {}: {}", source.describe(&dir), err))?;
+
+    let mut rec = Recorder::new(&args.render).map_err(|err| err.to_string())?;
+    let animated = day
+        .visualize(&content, &mut rec)
+        .map_err(|err| err.report(&content))?;
+    if !animated {
+        return Err(format!("day {} has nothing to render", day.day()));
+    }
+    let frames = rec
+        .finish()
+        .map_err(|err| format!("could not write the frames: {}", err))?;
+
+    // the terminal already showed them
+    match args.render.output {
+        Some(path) if args.render.format != Frames::Ansi => {
+            println!("{} frames written to {}", frames, path.display())
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let res = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Generate(args) => generate(args),
+        Command::Render(args) => render(args),
+    };
+
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
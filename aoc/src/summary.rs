@@ -0,0 +1,150 @@
+use std::iter;
+use std::time::{Duration, Instant};
+
+use aoc_common::input::{self, Source};
+use aoc_common::output::{self, Format};
+use aoc_common::{Answer, Day};
+use rayon::prelude::*;
+
+use crate::days;
+
+// how one day of a parallel run went
+struct Summary {
+    day: u32,
+    answers: Result<Vec<Answer>, String>,
+    elapsed: Duration,
+}
+
+fn solve(day: &dyn Day, source: &Source) -> Summary {
+    let start = Instant::now();
+
+    let dir = input::day_dir(day.day());
+    let answers = source
+        .read(&dir)
+        .map_err(|err| format!("could not read {}: {}", source.describe(&dir), err))
+        .and_then(|content| {
+            day.solve(&content, None)
+                .map_err(|err| err.report(&content))
+        });
+
+    Summary {
+        day: day.day(),
+        answers,
+        elapsed: start.elapsed(),
+    }
+}
+
+// what goes in the table for an answer, multi line ones are printed below it
+fn cell(answer: &Answer) -> String {
+    if answer.value.contains('\n') {
+        String::from("(below)")
+    } else {
+        answer.value.clone()
+    }
+}
+
+fn print_table(summaries: &[Summary]) {
+    let header = ["Day", "Part 1", "Part 2", "Time"]
+        .map(String::from)
+        .to_vec();
+    let rows = summaries
+        .iter()
+        .map(|summary| {
+            let mut row = vec![summary.day.to_string()];
+            match &summary.answers {
+                Ok(answers) => {
+                    row.extend(answers.iter().map(cell));
+                    row.resize(3, String::new());
+                }
+                Err(_) => row.extend([String::from("error"), String::new()]),
+            }
+            row.push(format!("{:.3} ms", summary.elapsed.as_secs_f64() * 1000.0));
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let table = iter::once(header).chain(rows).collect::<Vec<_>>();
+    let widths = (0..table[0].len())
+        .map(|i| {
+            table
+                .iter()
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for row in &table {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, &width))| match i {
+                // numbers line up on the right
+                0 | 3 => format!("{:>width$}", cell),
+                _ => format!("{:<width$}", cell),
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+
+    for summary in summaries {
+        match &summary.answers {
+            Ok(answers) => {
+                for answer in answers.iter().filter(|answer| answer.value.contains('\n')) {
+                    println!(
+                        "\nDay {} part {}:\n{}",
+                        summary.day, answer.part, answer.value
+                    );
+                }
+            }
+            Err(err) => println!("\nDay {}: {}", summary.day, err),
+        }
+    }
+}
+
+// runs every day on a pool of jobs threads (0 picks one per core), and
+// prints them in day order whatever order they finished in
+pub fn run(jobs: usize, source: &Source, format: Format) -> Result<(), String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|err| err.to_string())?;
+
+    let start = Instant::now();
+    let summaries = pool.install(|| {
+        days::DAYS
+            .par_iter()
+            .map(|day| solve(*day, source))
+            .collect::<Vec<_>>()
+    });
+    let elapsed = start.elapsed();
+
+    match format {
+        Format::Text => {
+            print_table(&summaries);
+            println!(
+                "\n{} days in {:.3} ms on {} threads",
+                summaries.len(),
+                elapsed.as_secs_f64() * 1000.0,
+                pool.current_num_threads()
+            );
+        }
+        Format::Json => {
+            for summary in &summaries {
+                match &summary.answers {
+                    Ok(answers) => output::print(summary.day, answers, format),
+                    Err(err) => eprintln!("error: {}", err),
+                }
+            }
+        }
+    }
+
+    let failed = summaries.iter().filter(|s| s.answers.is_err()).count();
+    if failed > 0 {
+        return Err(format!("{} days failed", failed));
+    }
+
+    Ok(())
+}
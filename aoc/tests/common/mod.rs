@@ -0,0 +1,11 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+// runs the aoc binary from the workspace root, where the day directories are
+pub fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+        .output()
+        .unwrap()
+}
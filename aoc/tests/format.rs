@@ -0,0 +1,25 @@
+use common::aoc;
+
+mod common;
+
+#[test]
+fn json_records_keep_answers_as_strings() {
+    let out = aoc(&["run", "--day", "25", "--example", "--format", "json"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+
+    assert!(out.status.success());
+    assert!(
+        stdout.starts_with(r#"{"day":25,"part":1,"answer":"2=-1=0","elapsed_ms":"#),
+        "{}",
+        stdout
+    );
+    assert_eq!(stdout.lines().count(), 1);
+}
+
+#[test]
+fn missing_parts_are_errors() {
+    let out = aoc(&["run", "--day", "25", "--part", "2", "--example"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("day 25 has no part 2"));
+    assert!(out.stdout.is_empty());
+}
@@ -0,0 +1,43 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use common::aoc;
+
+mod common;
+
+#[test]
+fn generated_inputs_are_solved() {
+    for day in 1..=25 {
+        let name = format!("aoc-generated-{}-day{}", process::id(), day);
+        let path = env::temp_dir().join(name);
+        let (day, path) = (day.to_string(), path.to_str().unwrap().to_string());
+
+        let out = aoc(&[
+            "generate", "--day", &day, "--size", "5", "--seed", "1", "-o", &path,
+        ]);
+        assert!(
+            out.status.success(),
+            "day {}: {}",
+            day,
+            String::from_utf8_lossy(&out.stderr)
+        );
+
+        let out = aoc(&["run", "--day", &day, "--input", &path]);
+        fs::remove_file(&path).unwrap();
+        assert!(
+            out.status.success(),
+            "day {}: {}",
+            day,
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+}
+
+#[test]
+fn same_seed_same_input() {
+    let generate = |seed| aoc(&["generate", "--day", "13", "--seed", seed]).stdout;
+
+    assert_eq!(generate("3"), generate("3"));
+    assert_ne!(generate("3"), generate("4"));
+}
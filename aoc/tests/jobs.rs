@@ -0,0 +1,27 @@
+use common::aoc;
+
+mod common;
+
+#[test]
+fn parallel_runs_are_printed_in_day_order() {
+    let out = aoc(&["run", "--all", "--example", "--jobs", "4"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+
+    assert!(out.status.success(), "{}", stdout);
+
+    let days = stdout
+        .lines()
+        .skip(1)
+        .take(25)
+        .map(|line| line.split_whitespace().next().unwrap())
+        .collect::<Vec<_>>();
+    let expected = (1..=25).map(|day| day.to_string()).collect::<Vec<_>>();
+    assert_eq!(days, expected);
+
+    assert!(
+        stdout.contains("\n 17  3068    1514285714288"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Day 10 part 2:\n██  ██"), "{}", stdout);
+}
@@ -0,0 +1,73 @@
+use aoc_common::Solution;
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+type Solve = fn(&str);
+
+// whatever parses has to be solved, too
+fn solve<S: Solution>(input: &str) {
+    if let Ok(input) = S::parse(input) {
+        S::part1(&input);
+        S::part2(&input);
+    }
+}
+
+// every day's solver, along with a valid input to start breaking from
+const DAYS: [(Solve, &str); 25] = [
+    (solve::<day1::Day1>, include_str!("../../day1/example")),
+    (solve::<day2::Day2>, include_str!("../../day2/example")),
+    (solve::<day3::Day3>, include_str!("../../day3/example")),
+    (solve::<day4::Day4>, include_str!("../../day4/example")),
+    (solve::<day5::Day5>, include_str!("../../day5/example")),
+    (solve::<day6::Day6>, include_str!("../../day6/example")),
+    (solve::<day7::Day7>, include_str!("../../day7/example")),
+    (solve::<day8::Day8>, include_str!("../../day8/example")),
+    (solve::<day9::Day9>, include_str!("../../day9/example")),
+    (solve::<day10::Day10>, include_str!("../../day10/example")),
+    (solve::<day11::Day11>, include_str!("../../day11/example")),
+    (solve::<day12::Day12>, include_str!("../../day12/example")),
+    (solve::<day13::Day13>, include_str!("../../day13/example")),
+    (solve::<day14::Day14>, include_str!("../../day14/example")),
+    (solve::<day15::Day15>, include_str!("../../day15/example")),
+    (solve::<day16::Day16>, include_str!("../../day16/example")),
+    (solve::<day17::Day17>, include_str!("../../day17/example")),
+    (solve::<day18::Day18>, include_str!("../../day18/example")),
+    (solve::<day19::Day19>, include_str!("../../day19/example")),
+    (solve::<day20::Day20>, include_str!("../../day20/example")),
+    (solve::<day21::Day21>, include_str!("../../day21/example")),
+    (solve::<day22::Day22>, include_str!("../../day22/example")),
+    (solve::<day23::Day23>, include_str!("../../day23/example")),
+    (solve::<day24::Day24>, include_str!("../../day24/example")),
+    (solve::<day25::Day25>, include_str!("../../day25/example")),
+];
+
+// the chars every day's input is made of, more or less
+const CHARS: &str = "[-=#.,:;<>\\[\\] 0-9a-zA-Z\n]";
+
+// replaces len chars at every (at, len, text) in turn with text
+fn edit(input: &str, edits: &[(Index, usize, String)]) -> String {
+    let mut chars = input.chars().collect::<Vec<_>>();
+    for (at, len, text) in edits {
+        let start = at.index(chars.len() + 1);
+        let end = (start + len).min(chars.len());
+        chars.splice(start..end, text.chars());
+    }
+    chars.into_iter().collect()
+}
+
+proptest! {
+    #[test]
+    fn edited_examples_are_errors_not_panics(
+        day in 0..25usize,
+        edits in prop::collection::vec((any::<Index>(), 0..8usize, prop::string::string_regex(&format!("{}{{0,4}}", CHARS)).unwrap()), 1..4),
+    ) {
+        let (solve, example) = DAYS[day];
+        solve(&edit(example, &edits));
+    }
+
+    #[test]
+    fn any_input_is_an_error_not_a_panic(day in 0..25usize, input in "\\PC{0,64}") {
+        let (solve, _) = DAYS[day];
+        solve(&input);
+    }
+}
@@ -0,0 +1,25 @@
+use common::aoc;
+
+mod common;
+
+#[test]
+fn profile_reports_every_phase() {
+    let out = aoc(&["run", "--day", "14", "--example", "--profile"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+
+    assert!(out.status.success());
+    // the answers are not mixed up with the report
+    assert_eq!(stdout, "Day 14\n1: 24\n2: 93\n");
+
+    let phases = stderr
+        .lines()
+        .skip(2)
+        .map(|line| line.split_whitespace().next().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(phases, ["read", "parse", "part", "part"], "{}", stderr);
+
+    // the parsed cave map has to live somewhere
+    let parse = stderr.lines().nth(3).unwrap();
+    assert!(!parse.ends_with(" 0 B"), "{}", stderr);
+}
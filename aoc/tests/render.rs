@@ -0,0 +1,100 @@
+use std::{env, fs, process};
+
+use common::aoc;
+
+mod common;
+
+#[test]
+fn every_animated_day_renders_its_example() {
+    for day in ["9", "14", "17", "23", "24"] {
+        let name = format!("aoc-render-{}-day{}.gif", process::id(), day);
+        let path = env::temp_dir().join(name);
+        let out = aoc(&[
+            "render",
+            "-d",
+            day,
+            "-e",
+            "-f",
+            "gif",
+            "-o",
+            path.to_str().unwrap(),
+        ]);
+        assert!(
+            out.status.success(),
+            "day {}: {}",
+            day,
+            String::from_utf8_lossy(&out.stderr)
+        );
+        let gif = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(gif.starts_with(b"GIF89a"), "day {}", day);
+    }
+}
+
+#[test]
+fn images_are_numbered_and_thinned_out() {
+    let dir = env::temp_dir().join(format!("aoc-render-{}-day14", process::id()));
+
+    let out = aoc(&[
+        "render", "-d", "14", "-e", "-f", "ppm", "--every", "10", "--scale", "2",
+    ]);
+    assert!(!out.status.success(), "ppm without an output directory");
+
+    let out = aoc(&[
+        "render",
+        "-d",
+        "14",
+        "-e",
+        "-f",
+        "ppm",
+        "-o",
+        dir.to_str().unwrap(),
+    ]);
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let all = fs::read_dir(&dir).unwrap().count();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let (dir, args) = (dir.to_str().unwrap(), ["--every", "10", "--scale", "2"]);
+    let out = aoc(&[
+        &["render", "-d", "14", "-e", "-f", "ppm", "-o", dir][..],
+        &args,
+    ]
+    .concat());
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let thinned = fs::read_dir(dir).unwrap().count();
+    let first = fs::read(format!("{}/frame00000.ppm", dir)).unwrap();
+    fs::remove_dir_all(dir).unwrap();
+
+    assert_eq!(thinned, all.div_ceil(10));
+    // the example cave is 31 tiles wide and 11 high, with room for the sand to spread
+    assert!(first.starts_with(b"P6\n62 22\n255\n"));
+}
+
+#[test]
+fn days_without_a_simulation_say_so() {
+    let out = aoc(&["render", "-d", "3", "-e"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("nothing to render"));
+
+    let dir = env::temp_dir().join(format!("aoc-render-none-{}", process::id()));
+    let out = aoc(&[
+        "render",
+        "-d",
+        "3",
+        "-e",
+        "-f",
+        "ppm",
+        "-o",
+        dir.to_str().unwrap(),
+    ]);
+    assert!(!out.status.success());
+    assert!(!dir.exists());
+}
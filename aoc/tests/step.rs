@@ -0,0 +1,85 @@
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+use common::aoc;
+
+mod common;
+
+// runs the debugger of a day on its example, with commands as the input
+fn step(day: &str, part: &str, commands: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "-d", day, "-p", part, "-e", "--step"])
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(commands.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn breakpoints_stop_the_run() {
+    let out = step("10", "1", "break cycle == 220\ncontinue\n");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(stdout.contains("breakpoint 1: cycle == 220"), "{}", stdout);
+    assert!(stdout.contains("cycle=220 x=19 signal=13140"), "{}", stdout);
+}
+
+#[test]
+fn steps_and_prints_the_state() {
+    let out = step("11", "1", "step 1\nprint\nquit\nstep\n");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("round=1 business=20"), "{}", stdout);
+    assert!(stdout.contains("Monkey 0: 20, 23, 27, 26\n"), "{}", stdout);
+    assert!(
+        !stdout.contains("round=2"),
+        "quit should stop reading commands"
+    );
+}
+
+#[test]
+fn runs_to_the_end_of_the_part() {
+    let out = step("14", "2", "c\nc\n");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(
+        stdout.matches("the simulation is over").count(),
+        2,
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("grains=93"), "{}", stdout);
+}
+
+#[test]
+fn bad_commands_are_explained() {
+    let out = step("23", "1", "break elves == 3\nbreak round ~ 3\njump\n");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("no counter elves, there are round, moved, empty"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("no operator ~"), "{}", stdout);
+    assert!(stdout.contains("unknown command `jump`"), "{}", stdout);
+}
+
+#[test]
+fn days_without_a_simulation_say_so() {
+    let out = aoc(&["run", "-d", "3", "-e", "--step"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("no simulation to step through"));
+}
@@ -0,0 +1,104 @@
+use std::fs;
+use std::process;
+
+use common::aoc;
+
+mod common;
+
+#[test]
+fn examples_match_recorded_answers() {
+    let out = aoc(&["verify", "--example"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+
+    assert!(out.status.success(), "{}", stdout);
+    assert!(
+        stdout.contains("49 answers checked, 0 failed"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn regressions_are_reported() {
+    let path = std::env::temp_dir().join(format!("aoc-answers-{}", process::id()));
+    fs::write(&path, "Day 1\n1: 24000\n2: 45001\n").unwrap();
+
+    let out = aoc(&[
+        "verify",
+        "--example",
+        "--day",
+        "1",
+        "--answers",
+        path.to_str().unwrap(),
+    ]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    fs::remove_file(&path).unwrap();
+
+    assert!(!out.status.success());
+    assert!(
+        stdout.contains("Day 1 part 2: expected 45001, got 45000"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("2 answers checked, 1 failed"), "{}", stdout);
+}
+
+#[test]
+fn missing_answers_fail() {
+    let path = std::env::temp_dir().join(format!("aoc-answers-missing-{}", process::id()));
+    fs::write(&path, "Day 1\n1: 24000\n").unwrap();
+
+    let out = aoc(&[
+        "verify",
+        "--example",
+        "--day",
+        "1",
+        "--answers",
+        path.to_str().unwrap(),
+    ]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    fs::remove_file(&path).unwrap();
+
+    assert!(!out.status.success());
+    assert!(stdout.contains("Day 1 part 2: no recorded answer"), "{}", stdout);
+    assert!(stdout.contains("2 answers checked, 1 failed"), "{}", stdout);
+}
+
+#[test]
+fn answers_no_longer_produced_fail() {
+    let path = std::env::temp_dir().join(format!("aoc-answers-extra-{}", process::id()));
+    fs::write(&path, "Day 25\n1: 2=-1=0\n2: 42\n").unwrap();
+
+    let out = aoc(&[
+        "verify",
+        "--example",
+        "--day",
+        "25",
+        "--answers",
+        path.to_str().unwrap(),
+    ]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    fs::remove_file(&path).unwrap();
+
+    assert!(!out.status.success());
+    assert!(
+        stdout.contains("Day 25 part 2: expected 42, got nothing"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("2 answers checked, 1 failed"), "{}", stdout);
+}
+
+#[test]
+fn skipped_days_with_recorded_answers_fail() {
+    let path = std::env::temp_dir().join(format!("aoc-answers-skipped-{}", process::id()));
+    // the example answers, against the puzzle input nobody checks in
+    fs::write(&path, "Day 1\n1: 24000\n2: 45000\n").unwrap();
+
+    let out = aoc(&["verify", "--day", "1", "--answers", path.to_str().unwrap()]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    fs::remove_file(&path).unwrap();
+
+    assert!(!out.status.success());
+    assert!(stdout.contains("2 answers checked, 2 failed"), "{}", stdout);
+}
@@ -0,0 +1,438 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Shl, Shr, Sub};
+
+// sets of small numbers, one bit each. BitSet holds 0..64 * W and is Copy,
+// GrowBitSet takes any number and grows as they come in
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BitSet<const W: usize = 1> {
+    words: [u64; W],
+}
+
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GrowBitSet {
+    // the first word lives inline, so sets of up to 64 never allocate.
+    // the rest never ends in a zero word, so equal sets have equal words
+    first: u64,
+    rest: Vec<u64>,
+}
+
+// the numbers in words, in increasing order
+fn ones(words: impl Iterator<Item = u64>) -> impl Iterator<Item = usize> {
+    words.enumerate().flat_map(|(k, word)| {
+        let mut word = word;
+        std::iter::from_fn(move || {
+            (word != 0).then(|| {
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                k * 64 + bit
+            })
+        })
+    })
+}
+
+impl<const W: usize> BitSet<W> {
+    pub const EMPTY: Self = Self { words: [0; W] };
+    pub const CAPACITY: usize = 64 * W;
+
+    pub fn new() -> Self {
+        Self::EMPTY
+    }
+
+    // whether i was not in the set yet
+    pub fn insert(&mut self, i: usize) -> bool {
+        assert!(
+            i < Self::CAPACITY,
+            "{} does not fit in a set of {}",
+            i,
+            Self::CAPACITY
+        );
+        let (word, bit) = (&mut self.words[i / 64], 1 << (i % 64));
+        let new = *word & bit == 0;
+        *word |= bit;
+        new
+    }
+
+    // whether i was in the set
+    pub fn remove(&mut self, i: usize) -> bool {
+        if i >= Self::CAPACITY {
+            return false;
+        }
+        let (word, bit) = (&mut self.words[i / 64], 1 << (i % 64));
+        let had = *word & bit != 0;
+        *word &= !bit;
+        had
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        i < Self::CAPACITY && self.words[i / 64] & 1 << (i % 64) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(a, b)| a & !b == 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        ones(self.words.iter().copied())
+    }
+
+    // the smallest number in the set
+    pub fn first(&self) -> Option<usize> {
+        self.iter().next()
+    }
+
+    fn zip_with(mut self, other: Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        for (a, b) in self.words.iter_mut().zip(other.words) {
+            *a = f(*a, b);
+        }
+        self
+    }
+}
+
+impl BitSet<1> {
+    // the set with the bits of a single word, as a bitmask
+    pub const fn from_bits(bits: u64) -> Self {
+        Self { words: [bits] }
+    }
+
+    pub const fn bits(self) -> u64 {
+        self.words[0]
+    }
+}
+
+impl<const W: usize> Default for BitSet<W> {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl<const W: usize> BitOr for BitSet<W> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+}
+
+impl<const W: usize> BitAnd for BitSet<W> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+}
+
+impl<const W: usize> BitOrAssign for BitSet<W> {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = *self | other;
+    }
+}
+
+impl<const W: usize> BitAndAssign for BitSet<W> {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = *self & other;
+    }
+}
+
+impl<const W: usize> Sub for BitSet<W> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a & !b)
+    }
+}
+
+// adds n to every number, dropping those that no longer fit
+impl<const W: usize> Shl<usize> for BitSet<W> {
+    type Output = Self;
+
+    fn shl(self, n: usize) -> Self {
+        let (skip, bits) = (n / 64, n % 64);
+        let mut res = Self::EMPTY;
+        for k in skip..W {
+            res.words[k] = self.words[k - skip] << bits;
+            if bits > 0 && k > skip {
+                res.words[k] |= self.words[k - skip - 1] >> (64 - bits);
+            }
+        }
+        res
+    }
+}
+
+// takes n from every number, dropping those that go below 0
+impl<const W: usize> Shr<usize> for BitSet<W> {
+    type Output = Self;
+
+    fn shr(self, n: usize) -> Self {
+        let (skip, bits) = (n / 64, n % 64);
+        let mut res = Self::EMPTY;
+        for k in 0..W.saturating_sub(skip) {
+            res.words[k] = self.words[k + skip] >> bits;
+            if bits > 0 && k + skip + 1 < W {
+                res.words[k] |= self.words[k + skip + 1] << (64 - bits);
+            }
+        }
+        res
+    }
+}
+
+impl<const W: usize> FromIterator<usize> for BitSet<W> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::EMPTY;
+        for i in iter {
+            set.insert(i);
+        }
+        set
+    }
+}
+
+// word by word, without the length a slice would hash first
+impl<const W: usize> Hash for BitSet<W> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for &word in &self.words {
+            state.write_u64(word);
+        }
+    }
+}
+
+impl<const W: usize> fmt::Debug for BitSet<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl GrowBitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn word(&self, k: usize) -> u64 {
+        match k {
+            0 => self.first,
+            _ => self.rest.get(k - 1).copied().unwrap_or(0),
+        }
+    }
+
+    fn words(&self) -> impl Iterator<Item = u64> + '_ {
+        std::iter::once(self.first).chain(self.rest.iter().copied())
+    }
+
+    fn from_words(words: impl Iterator<Item = u64>) -> Self {
+        let mut words = words;
+        let mut res = Self {
+            first: words.next().unwrap_or(0),
+            rest: words.collect(),
+        };
+        res.trim();
+        res
+    }
+
+    pub fn insert(&mut self, i: usize) -> bool {
+        let word = match i / 64 {
+            0 => &mut self.first,
+            k => {
+                if self.rest.len() < k {
+                    self.rest.resize(k, 0);
+                }
+                &mut self.rest[k - 1]
+            }
+        };
+        let bit = 1 << (i % 64);
+        let new = *word & bit == 0;
+        *word |= bit;
+        new
+    }
+
+    pub fn remove(&mut self, i: usize) -> bool {
+        let word = match i / 64 {
+            0 => &mut self.first,
+            k => match self.rest.get_mut(k - 1) {
+                Some(word) => word,
+                None => return false,
+            },
+        };
+        let bit = 1 << (i % 64);
+        let had = *word & bit != 0;
+        *word &= !bit;
+        self.trim();
+        had
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        self.word(i / 64) & 1 << (i % 64) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.words().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first == 0 && self.rest.is_empty()
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words().zip(other.words()).all(|(a, b)| a & b == 0)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words()
+            .enumerate()
+            .all(|(k, a)| a & !other.word(k) == 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        ones(self.words())
+    }
+
+    pub fn first(&self) -> Option<usize> {
+        self.iter().next()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let n = self.rest.len().max(other.rest.len()) + 1;
+        Self::from_words((0..n).map(|k| self.word(k) | other.word(k)))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::from_words(self.words().zip(other.words()).map(|(a, b)| a & b))
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        Self::from_words(self.words().enumerate().map(|(k, a)| a & !other.word(k)))
+    }
+
+    fn trim(&mut self) {
+        while self.rest.last() == Some(&0) {
+            self.rest.pop();
+        }
+    }
+}
+
+impl FromIterator<usize> for GrowBitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for i in iter {
+            set.insert(i);
+        }
+        set
+    }
+}
+
+// hashes the same as a BitSet of as many words would
+impl Hash for GrowBitSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for word in self.words() {
+            state.write_u64(word);
+        }
+    }
+}
+
+impl fmt::Debug for GrowBitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+
+    use super::*;
+
+    fn hash(x: &impl Hash) -> u64 {
+        let mut h = DefaultHasher::new();
+        x.hash(&mut h);
+        h.finish()
+    }
+
+    fn set<const W: usize>(xs: &[usize]) -> BitSet<W> {
+        xs.iter().copied().collect()
+    }
+
+    #[test]
+    fn shifts_cross_words() {
+        let s = set::<3>(&[0, 63, 64, 127, 150]);
+        assert_eq!(s << 1, set(&[1, 64, 65, 128, 151]));
+        assert_eq!(s << 64, set(&[64, 127, 128, 191]));
+        assert_eq!(s << 70, set(&[70, 133, 134]));
+        assert_eq!(s >> 1, set(&[62, 63, 126, 149]));
+        assert_eq!(s >> 64, set(&[0, 63, 86]));
+        assert_eq!(s >> 100, set(&[27, 50]));
+        assert_eq!(s << 192, BitSet::EMPTY);
+        assert_eq!(s >> 192, BitSet::EMPTY);
+    }
+
+    #[test]
+    fn set_operations() {
+        let (a, b) = (set::<2>(&[1, 70, 100]), set::<2>(&[1, 100, 127]));
+        assert_eq!(a | b, set(&[1, 70, 100, 127]));
+        assert_eq!(a & b, set(&[1, 100]));
+        assert_eq!(a - b, set(&[70]));
+        assert!((a & b).is_subset(&a) && !a.is_subset(&b));
+        assert!((a - b).is_disjoint(&b));
+        assert_eq!(a.iter().collect::<Vec<_>>(), [1, 70, 100]);
+        assert_eq!((a.len(), a.first()), (3, Some(1)));
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut s = BitSet::<2>::new();
+        assert!(s.insert(127) && !s.insert(127));
+        assert!(s.contains(127) && !s.contains(128));
+        assert!(s.remove(127) && !s.remove(127) && !s.remove(500));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_past_the_capacity() {
+        BitSet::<1>::new().insert(64);
+    }
+
+    #[test]
+    fn growable_sets_trim_to_stay_equal() {
+        let mut a = GrowBitSet::from_iter([3, 200]);
+        a.remove(200);
+        let b = GrowBitSet::from_iter([3]);
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        assert_eq!(hash(&b), hash(&set::<1>(&[3])));
+
+        let big = GrowBitSet::from_iter([3, 300]);
+        assert_eq!(big.difference(&GrowBitSet::from_iter([300])), b);
+        assert_eq!(big.intersection(&b), b);
+        assert_eq!(b.union(&GrowBitSet::from_iter([300])), big);
+    }
+
+    #[test]
+    fn growable_sets_of_different_lengths() {
+        let (short, long) = (
+            GrowBitSet::from_iter([1, 5]),
+            GrowBitSet::from_iter([1, 5, 130]),
+        );
+        assert!(short.is_subset(&long) && !long.is_subset(&short));
+        assert!(long.difference(&short) == GrowBitSet::from_iter([130]));
+        assert!(short.difference(&long).is_empty());
+        assert!(!short.is_disjoint(&long));
+        assert!(GrowBitSet::from_iter([130]).is_disjoint(&short));
+        assert!(!long.contains(1000) && !GrowBitSet::new().remove(1000));
+        assert_eq!(long.iter().collect::<Vec<_>>(), [1, 5, 130]);
+        assert_eq!(long.len(), 3);
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// the metric of state after n steps, for a process that eventually repeats.
+// once two states share a key they are taken to be at the same point of a
+// cycle, over which the metric grows by the same amount every time round, so
+// the key has to capture everything the future of the state depends on
+pub fn extrapolate<S, St, K, Key, M>(
+    mut state: S,
+    n: usize,
+    mut step: St,
+    mut key: K,
+    mut metric: M,
+) -> i64
+where
+    St: FnMut(&mut S),
+    K: FnMut(&S) -> Key,
+    Key: Eq + Hash,
+    M: FnMut(&S) -> i64,
+{
+    // the metric after every step so far, and the step every key was first seen at
+    let mut history = Vec::new();
+    let mut seen = HashMap::new();
+
+    for i in 0.. {
+        history.push(metric(&state));
+        if i == n {
+            break;
+        }
+
+        if let Some(start) = seen.insert(key(&state), i) {
+            let len = i - start;
+            let per_cycle = history[i] - history[start];
+            let (cycles, rest) = ((n - start) / len, (n - start) % len);
+            return history[start + rest] + cycles as i64 * per_cycle;
+        }
+
+        step(&mut state);
+    }
+
+    history[n]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 1 2 3 4 then 2 3 4 over and over, adding up the positions as it goes
+    fn next(&(pos, sum): &(i64, i64)) -> (i64, i64) {
+        let pos = if pos == 4 { 2 } else { pos + 1 };
+        (pos, sum + pos)
+    }
+
+    fn sum_after(n: usize) -> i64 {
+        extrapolate((0, 0), n, |s| *s = next(s), |s| s.0, |s| s.1)
+    }
+
+    fn naive(n: usize) -> i64 {
+        (0..n).fold((0, 0), |s, _| next(&s)).1
+    }
+
+    #[test]
+    fn before_any_repeat() {
+        assert_eq!(sum_after(0), 0);
+        assert_eq!(sum_after(3), 1 + 2 + 3);
+        assert_eq!(sum_after(4), 1 + 2 + 3 + 4);
+    }
+
+    #[test]
+    fn on_the_start_of_a_cycle() {
+        // the cycle starts at step 2, 3 steps long and adding 9 every time round
+        assert_eq!(sum_after(2), 3);
+        assert_eq!(sum_after(5), 3 + 9);
+        assert_eq!(sum_after(2 + 3 * 1000), 3 + 9 * 1000);
+    }
+
+    #[test]
+    fn partway_into_a_cycle() {
+        assert_eq!(sum_after(2 + 3 * 1000 + 1), 3 + 9 * 1000 + 3);
+        assert_eq!(sum_after(2 + 3 * 1000 + 2), 3 + 9 * 1000 + 3 + 4);
+        for n in 0..100 {
+            assert_eq!(sum_after(n), naive(n), "after {} steps", n);
+        }
+    }
+
+    #[test]
+    fn stops_stepping_once_the_cycle_is_found() {
+        let mut steps = 0;
+        let sum = extrapolate(
+            (0, 0),
+            1_000_000_000_000,
+            |s| {
+                steps += 1;
+                *s = next(s);
+            },
+            |s| s.0,
+            |s| s.1,
+        );
+        assert_eq!(
+            sum,
+            3 + 9 * ((1_000_000_000_000 - 2) / 3) + [0, 3, 7][(1_000_000_000_000 - 2) % 3]
+        );
+        assert!(steps < 10);
+    }
+}
@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::fmt;
+
+// a parse failure, pointing at the line and column (both starting from 1) where it happened
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u32>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    pub fn in_day(mut self, day: u32) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    // renders the error together with the offending line of the input
+    pub fn report(&self, input: &str) -> String {
+        let mut res = self.to_string();
+
+        if let Some(line) = input.lines().nth(self.line - 1) {
+            let gutter = " ".repeat(self.line.to_string().len());
+            res.push_str(&format!("\n{} |\n{} | {}\n", gutter, self.line, line));
+            res.push_str(&format!("{} | {}^", gutter, " ".repeat(self.column - 1)));
+        }
+
+        res
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,323 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use crate::parse;
+use crate::point::Point2;
+
+// a (row, column) position in a grid
+pub type Point = (usize, usize);
+
+const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+// a rectangular grid of cells, stored row after row in a single vec
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(Point) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|i| (0..width).map(move |j| (i, j)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // parses one cell out of every char, every row as wide as the first one.
+    // cell returns None for chars that are not cells, `what` describes a cell
+    pub fn parse<F>(input: &str, what: &str, mut cell: F) -> parse::Result<Self>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+
+        let rows = parse::lines(input, |s| {
+            let start = cells.len();
+            while width.is_none_or(|width| cells.len() - start < width) {
+                match s.peek().and_then(&mut cell) {
+                    Some(value) => cells.push(value),
+                    None => break,
+                }
+                s.bump();
+            }
+
+            match (cells.len() - start, width) {
+                (0, _) => Err(s.error(what)),
+                (row, Some(width)) if row < width => Err(s.error(what)),
+                (row, _) => {
+                    width = Some(row);
+                    Ok(())
+                }
+            }
+        })?;
+
+        if rows.is_empty() {
+            return Err(parse::missing(input, what));
+        }
+
+        Ok(Self {
+            width: width.unwrap(),
+            height: rows.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (i, j): Point) -> bool {
+        i < self.height && j < self.width
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p).then(|| &self[p])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self[p])
+        } else {
+            None
+        }
+    }
+
+    // p moved by (di, dj), if that is still inside the grid
+    pub fn offset(&self, (i, j): Point, (di, dj): (isize, isize)) -> Option<Point> {
+        let p = (i.checked_add_signed(di)?, j.checked_add_signed(dj)?);
+        self.contains(p).then_some(p)
+    }
+
+    // p moved by delta, whose x runs along the row and y down the column
+    pub fn step(&self, p: Point, delta: Point2) -> Option<Point> {
+        self.offset(p, (delta.y as isize, delta.x as isize))
+    }
+
+    // the up to 4 points sharing a side with p
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS4
+            .into_iter()
+            .filter_map(move |d| self.offset(p, d))
+    }
+
+    // the up to 8 points sharing a side or a corner with p
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS8
+            .into_iter()
+            .filter_map(move |d| self.offset(p, d))
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        &mut self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // the cells of column j, from the top row down
+    pub fn column(&self, j: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(j < self.width, "column {} is outside the grid", j);
+        self.cells[j..].iter().step_by(self.width)
+    }
+
+    pub fn cells(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    pub fn position<F>(&self, mut pred: F) -> Option<Point>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let k = self.cells.iter().position(&mut pred)?;
+        Some((k / self.width, k % self.width))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // one line of text per row, with a char for every cell
+    pub fn render<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let mut res = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            res.extend(row.iter().map(&mut f));
+            res.push('\n');
+        }
+        res
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): Point) -> &T {
+        assert!(self.contains((i, j)), "({}, {}) is outside the grid", i, j);
+        &self.cells[i * self.width + j]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, (i, j): Point) -> &mut T {
+        assert!(self.contains((i, j)), "({}, {}) is outside the grid", i, j);
+        &mut self.cells[i * self.width + j]
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&c| c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> parse::Result<Grid<u32>> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_rows_as_wide_as_the_first() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 4);
+
+        let err = digits("123\n45\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 3, "a digit")
+        );
+        let err = digits("123\n4567\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_first_bad_cell() {
+        let err = digits("12x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        let err = digits("\n123\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert!(digits("").is_err());
+    }
+
+    #[test]
+    fn steps_stop_at_the_edges() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (0, -1)), None);
+        assert_eq!(grid.offset((1, 2), (1, 0)), None);
+        assert_eq!(grid.offset((1, 2), (0, 1)), None);
+        assert_eq!(grid.offset((0, 0), (1, 2)), Some((1, 2)));
+
+        // x along the row and y down the column
+        assert_eq!(grid.step((0, 0), Point2::new(1, 0)), Some((0, 1)));
+        assert_eq!(grid.step((0, 0), Point2::new(0, 1)), Some((1, 0)));
+        assert_eq!(grid.step((0, 2), Point2::new(1, 0)), None);
+        assert_eq!(grid.step((0, 0), Point2::new(0, -1)), None);
+    }
+
+    #[test]
+    fn neighbors_at_the_corners() {
+        let grid = Grid::new(3, 3, 0);
+        let sorted = |mut points: Vec<Point>| {
+            points.sort();
+            points
+        };
+
+        assert_eq!(sorted(grid.neighbors4((0, 0)).collect()), [(0, 1), (1, 0)]);
+        assert_eq!(sorted(grid.neighbors4((2, 2)).collect()), [(1, 2), (2, 1)]);
+        assert_eq!(
+            sorted(grid.neighbors8((0, 0)).collect()),
+            [(0, 1), (1, 0), (1, 1)]
+        );
+        assert_eq!(
+            sorted(grid.neighbors8((2, 0)).collect()),
+            [(1, 0), (1, 1), (2, 1)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_fn(3, 2, |(i, j)| 10 * i + j);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 1, 2], [10, 11, 12]]);
+        assert_eq!(grid.row(1), [10, 11, 12]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [2, 12]);
+        assert_eq!(grid.column(0).rev().copied().collect::<Vec<_>>(), [10, 0]);
+        assert_eq!(grid.column(1).len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn columns_past_the_width() {
+        let _ = Grid::from_fn(3, 2, |(i, j)| 10 * i + j).column(3);
+    }
+}
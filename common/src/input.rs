@@ -0,0 +1,73 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+pub const INPUT: &str = "input";
+pub const EXAMPLE: &str = "example";
+
+// where a puzzle input comes from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Puzzle,
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    // dir is where the puzzle and example files of the day live
+    pub fn read(&self, dir: &Path) -> io::Result<String> {
+        match self {
+            Source::Puzzle => read(dir.join(INPUT)),
+            Source::Example => read(dir.join(EXAMPLE)),
+            Source::File(path) => read(path),
+            Source::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+                Ok(content)
+            }
+        }
+    }
+
+    pub fn describe(&self, dir: &Path) -> String {
+        match self {
+            Source::Puzzle => dir.join(INPUT).display().to_string(),
+            Source::Example => dir.join(EXAMPLE).display().to_string(),
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => String::from("stdin"),
+        }
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct InputArgs {
+    /// Read the input from this file instead, `-` reads it from stdin
+    #[arg(short, long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// Use the puzzle example instead of the real input
+    #[arg(short, long, conflicts_with = "input")]
+    pub example: bool,
+}
+
+impl InputArgs {
+    pub fn source(&self) -> Source {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::File(path.clone()),
+            None if self.example => Source::Example,
+            None => Source::Puzzle,
+        }
+    }
+}
+
+pub fn read(path: impl AsRef<Path>) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+// where the files of a day live, relative to the workspace root
+pub fn day_dir(day: u32) -> PathBuf {
+    PathBuf::from(format!("day{}", day))
+}
@@ -0,0 +1,17 @@
+pub mod bitset;
+pub mod cycle;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod point;
+pub mod profile;
+pub mod random;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod step;
+
+pub use error::ParseError;
+pub use solution::{Answer, Day, Solution};
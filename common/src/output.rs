@@ -0,0 +1,39 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::Answer;
+
+// how answers are printed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Plain `part: answer` lines
+    #[default]
+    Text,
+    /// One JSON record per answer, with the answer as a string
+    Json,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u32,
+    part: u32,
+    answer: &'a str,
+    elapsed_ms: f64,
+}
+
+pub fn print(day: u32, answers: &[Answer], format: Format) {
+    for answer in answers {
+        match format {
+            Format::Text => println!("{}", answer),
+            Format::Json => {
+                let record = Record {
+                    day,
+                    part: answer.part,
+                    answer: &answer.value,
+                    elapsed_ms: answer.elapsed.as_secs_f64() * 1000.0,
+                };
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub use crate::error::ParseError;
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+// a cursor over some input text that knows which line and column it is on,
+// so every parser can report exactly where and what it expected
+#[derive(Clone)]
+pub struct Scanner<'a> {
+    src: &'a str,
+    pos: usize,
+    line: usize,
+    // what running out of src means, for error messages
+    end: &'static str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Self {
+            src,
+            pos: 0,
+            line: 1,
+            end: "end of input",
+        }
+    }
+
+    // a scanner over a single line of the whole input
+    pub fn at_line(src: &'a str, line: usize) -> Self {
+        Self {
+            src,
+            pos: 0,
+            line,
+            end: "end of line",
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pos == self.src.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    // line and column of the current position
+    pub fn position(&self) -> (usize, usize) {
+        let before = &self.src[..self.pos];
+        let line = self.line + before.matches('\n').count();
+        let start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[start..].chars().count() + 1)
+    }
+
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let (line, column) = self.position();
+        let found = match self.peek() {
+            None => String::from(self.end),
+            Some('\n') => String::from("end of line"),
+            Some(c) => format!("`{}`", c),
+        };
+        ParseError::new(line, column, expected, found)
+    }
+
+    // consumes lit if the input continues with it
+    pub fn eat(&mut self, lit: &str) -> bool {
+        if self.rest().starts_with(lit) {
+            self.pos += lit.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, lit: &str) -> Result<()> {
+        if self.eat(lit) {
+            return Ok(());
+        }
+
+        // point at the first character that differs
+        let common = lit
+            .chars()
+            .zip(self.rest().chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum::<usize>();
+
+        let mut at = self.clone();
+        at.pos += common;
+        Err(at.error(format!("`{}`", lit.escape_debug())))
+    }
+
+    // consumes the first of the options the input continues with
+    pub fn choice<'b>(&mut self, options: &[&'b str]) -> Result<&'b str> {
+        for option in options {
+            if self.eat(option) {
+                return Ok(option);
+            }
+        }
+
+        let expected = options
+            .iter()
+            .map(|o| format!("`{}`", o.escape_debug()))
+            .collect::<Vec<_>>();
+        Err(self.error(format!("one of {}", expected.join(", "))))
+    }
+
+    pub fn take_while<F>(&mut self, pred: F) -> &'a str
+    where
+        F: Fn(char) -> bool,
+    {
+        let start = self.pos;
+        while self.peek().is_some_and(&pred) {
+            self.bump();
+        }
+        &self.src[start..self.pos]
+    }
+
+    // a non empty run of letters and digits
+    pub fn word(&mut self) -> Result<&'a str> {
+        let word = self.take_while(|c| c.is_ascii_alphanumeric());
+        if word.is_empty() {
+            Err(self.error("a name"))
+        } else {
+            Ok(word)
+        }
+    }
+
+    // an optionally signed integer
+    pub fn number<T: FromStr>(&mut self) -> Result<T> {
+        let start = self.clone();
+        let sign = if self.eat("-") { "-" } else { "" };
+        let digits = self.take_while(|c| c.is_ascii_digit());
+
+        if digits.is_empty() {
+            *self = start;
+            return Err(self.error("a number"));
+        }
+
+        format!("{}{}", sign, digits).parse().map_err(|_| {
+            let err = start.error("a number that fits");
+            *self = start;
+            err
+        })
+    }
+
+    // an integer in lo..=hi
+    pub fn number_in<T>(&mut self, lo: T, hi: T) -> Result<T>
+    where
+        T: FromStr + PartialOrd + Display,
+    {
+        let start = self.clone();
+        let x = self.number()?;
+
+        if x < lo || x > hi {
+            *self = start;
+            return Err(self.error(format!("a number between {} and {}", lo, hi)));
+        }
+
+        Ok(x)
+    }
+
+    // consumes the rest of the current line, including its line ending
+    pub fn line(&mut self) -> &'a str {
+        let line = self.take_while(|c| c != '\n');
+        self.eat("\n");
+        line.strip_suffix('\r').unwrap_or(line)
+    }
+
+    pub fn finish(&self) -> Result<()> {
+        if self.is_done() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+// runs parser on every line of the input, which must consume the whole line
+pub fn lines<'a, T, F>(input: &'a str, mut parser: F) -> Result<Vec<T>>
+where
+    F: FnMut(&mut Scanner<'a>) -> Result<T>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut s = Scanner::at_line(line, i + 1);
+            let res = parser(&mut s)?;
+            s.finish()?;
+            Ok(res)
+        })
+        .collect()
+}
+
+// the groups of lines between blank lines, a scanner per line, read as they
+// are asked for. runs of blank lines count as one and the last group needs no
+// blank line after it
+pub fn groups(input: &str) -> impl Iterator<Item = Vec<Scanner<'_>>> {
+    let mut lines = input.lines().enumerate().peekable();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(|(_, line)| line.is_empty()).is_some() {}
+
+        let mut group = Vec::new();
+        while let Some((i, line)) = lines.next_if(|(_, line)| !line.is_empty()) {
+            group.push(Scanner::at_line(line, i + 1));
+        }
+        (!group.is_empty()).then_some(group)
+    })
+}
+
+// an error at the very end of the input, for things that are missing altogether
+pub fn missing(input: &str, expected: impl Into<String>) -> ParseError {
+    let mut s = Scanner::new(input);
+    s.pos = input.len();
+    s.error(expected)
+}
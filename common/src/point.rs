@@ -0,0 +1,278 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// points on the plane follow the screen (and grid) convention: x grows to the
+// right and y grows downwards, so Dir4::Up is (0, -1)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // moves in any of the 8 directions count as 1
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    // every coordinate as -1, 0 or 1, a single step towards where self points
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    // quarter turns around the origin, as seen on screen
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Dir4::ALL.into_iter().map(move |d| self + d.delta())
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Dir8::ALL.into_iter().map(move |d| self + d.delta())
+    }
+}
+
+impl Point3 {
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        let d = self - other;
+        d.x.abs().max(d.y.abs()).max(d.z.abs())
+    }
+
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    // the 6 points sharing a face with self
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        [
+            Self::new(1, 0, 0),
+            Self::new(-1, 0, 0),
+            Self::new(0, 1, 0),
+            Self::new(0, -1, 0),
+            Self::new(0, 0, 1),
+            Self::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |d| self + d)
+    }
+}
+
+// the arithmetic works the same, coordinate by coordinate, for both points
+macro_rules! impl_ops {
+    ($point:ident, $($c:ident),+) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, k: i64) -> Self {
+                Self { $($c: self.$c * k),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2, x, y);
+impl_ops!(Point3, x, y, z);
+
+// the four directions along the axes, in clockwise order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Self; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir4::Up => Point2::new(0, -1),
+            Dir4::Right => Point2::new(1, 0),
+            Dir4::Down => Point2::new(0, 1),
+            Dir4::Left => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+// the four directions of Dir4 and the diagonals between them, in clockwise order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    pub const ALL: [Self; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir8::N => Point2::new(0, -1),
+            Dir8::NE => Point2::new(1, -1),
+            Dir8::E => Point2::new(1, 0),
+            Dir8::SE => Point2::new(1, 1),
+            Dir8::S => Point2::new(0, 1),
+            Dir8::SW => Point2::new(-1, 1),
+            Dir8::W => Point2::new(-1, 0),
+            Dir8::NW => Point2::new(-1, -1),
+        }
+    }
+
+    // eighth turns
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dir4_turns() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Right.reverse(), Dir4::Left);
+
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.reverse().delta(), -dir.delta());
+            assert_eq!(dir.turn_right().delta(), dir.delta().rotate_right());
+        }
+    }
+
+    #[test]
+    fn dir8_turns() {
+        assert_eq!(Dir8::N.turn_right(), Dir8::NE);
+        assert_eq!(Dir8::NW.turn_right(), Dir8::N);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::NE.reverse(), Dir8::SW);
+
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.reverse().delta(), -dir.delta());
+            let quarter = dir.turn_right().turn_right();
+            assert_eq!(quarter.delta(), dir.delta().rotate_right());
+        }
+    }
+
+    #[test]
+    fn dir8_from_dir4() {
+        assert_eq!(Dir8::from(Dir4::Up), Dir8::N);
+        assert_eq!(Dir8::from(Dir4::Left), Dir8::W);
+        for dir in Dir4::ALL {
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+// bytes allocated right now, and the most there were since the last reset
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// the system allocator, keeping count of the bytes in use, so a binary that
+// installs it with #[global_allocator] gets allocation numbers out of measure
+pub struct Counting;
+
+fn grow(size: usize) {
+    let now = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(now, Ordering::Relaxed);
+}
+
+fn shrink(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                shrink(layout.size() - new_size);
+            }
+        }
+        new
+    }
+}
+
+// one step of solving a day, like reading its input or running part 1
+pub struct Phase {
+    pub name: String,
+    pub elapsed: Duration,
+    // most bytes allocated at once on top of what already was when the phase
+    // started, always 0 without the Counting allocator
+    pub peak: usize,
+}
+
+pub fn measure<T>(name: impl Into<String>, f: impl FnOnce() -> T) -> (T, Phase) {
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+
+    let start = Instant::now();
+    let res = f();
+    let elapsed = start.elapsed();
+
+    let phase = Phase {
+        name: name.into(),
+        elapsed,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(base),
+    };
+    (res, phase)
+}
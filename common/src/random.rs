@@ -0,0 +1,51 @@
+// a small seeded generator (splitmix64) for making up inputs, so the same seed
+// gives the same input on every machine and with every version of the crate
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // uniform in lo..=hi
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+        let span = hi.abs_diff(lo) as u128 + 1;
+        lo.wrapping_add((self.next_u64() as u128 % span) as i64)
+    }
+
+    // uniform in 0..n
+    pub fn index(&mut self, n: usize) -> usize {
+        assert!(n > 0, "no index below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // uniform in lo..=hi, for ascii chars
+    pub fn char_in(&mut self, lo: char, hi: char) -> char {
+        self.range(lo as i64, hi as i64) as u8 as char
+    }
+
+    // true with probability p
+    pub fn chance(&mut self, p: f64) -> bool {
+        let x = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        x < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
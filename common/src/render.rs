@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use clap::{Args, ValueEnum};
+
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+// the state of a simulation at one point in time, a color per tile
+pub type Frame = Grid<Rgb>;
+
+// what the frames end up as
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// An animation in the terminal, two tiles per character
+    #[default]
+    Ansi,
+    /// A numbered PPM image per frame, in the output directory
+    Ppm,
+    /// A numbered PNG image per frame, in the output directory
+    Png,
+    /// A single animated GIF, written to the output file
+    Gif,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct RenderArgs {
+    /// What to render the frames as
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+
+    /// Directory for the images, or file for the gif
+    #[arg(
+        short,
+        long,
+        value_name = "PATH",
+        required_if_eq_any = [("format", "ppm"), ("format", "png"), ("format", "gif")]
+    )]
+    pub output: Option<PathBuf>,
+
+    /// Side of a tile in pixels, in images and gifs
+    #[arg(long, default_value_t = 4)]
+    pub scale: usize,
+
+    /// Only keep every Nth frame
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    pub every: usize,
+
+    /// Time between frames in milliseconds, in the terminal and gifs
+    #[arg(long, value_name = "MS", default_value_t = 50)]
+    pub delay: u64,
+}
+
+enum Sink {
+    Ansi {
+        out: io::Stdout,
+        size: Option<(usize, usize)>,
+    },
+    // the directory is made with the first frame, so days without any leave none behind
+    Images {
+        dir: PathBuf,
+        png: bool,
+    },
+    // the encoder is made with the first frame, which sets the size of all of them
+    Gif {
+        path: PathBuf,
+        encoder: Option<gif::Encoder<BufWriter<File>>>,
+    },
+}
+
+// takes the frames of a simulation as it runs and writes them out. writing
+// can fail halfway through a day, so the first error is kept for finish
+pub struct Recorder {
+    sink: Sink,
+    scale: usize,
+    every: usize,
+    delay: Duration,
+    // frames seen and frames written
+    seen: usize,
+    written: usize,
+    error: Option<io::Error>,
+}
+
+impl Recorder {
+    pub fn new(args: &RenderArgs) -> io::Result<Self> {
+        let output = || {
+            args.output
+                .clone()
+                .ok_or_else(|| io::Error::other("an output path is needed for images"))
+        };
+
+        let sink = match args.format {
+            Format::Ansi => Sink::Ansi {
+                out: io::stdout(),
+                size: None,
+            },
+            Format::Ppm | Format::Png => Sink::Images {
+                dir: output()?,
+                png: args.format == Format::Png,
+            },
+            Format::Gif => Sink::Gif {
+                path: output()?,
+                encoder: None,
+            },
+        };
+
+        Ok(Self {
+            sink,
+            scale: args.scale.max(1),
+            every: args.every.max(1),
+            delay: Duration::from_millis(args.delay),
+            seen: 0,
+            written: 0,
+            error: None,
+        })
+    }
+
+    // adds the next frame, only drawing it if it is one of those kept
+    pub fn record<F>(&mut self, frame: F)
+    where
+        F: FnOnce() -> Frame,
+    {
+        self.seen += 1;
+        if !(self.seen - 1).is_multiple_of(self.every) || self.error.is_some() {
+            return;
+        }
+
+        if let Err(err) = self.write(&frame()) {
+            self.error = Some(err);
+        }
+        self.written += 1;
+    }
+
+    // the number of frames written
+    pub fn finish(self) -> io::Result<usize> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        match self.sink {
+            Sink::Ansi { mut out, .. } => out.flush()?,
+            Sink::Gif { encoder, .. } => {
+                if let Some(encoder) = encoder {
+                    encoder.into_inner()?.flush()?;
+                }
+            }
+            Sink::Images { .. } => {}
+        }
+
+        Ok(self.written)
+    }
+
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        let (scale, delay, n) = (self.scale, self.delay, self.written);
+
+        match &mut self.sink {
+            Sink::Ansi { out, size } => {
+                // clear the screen when the size changes, or the old frame shows around the new one
+                let clear = *size != Some((frame.width(), frame.height()));
+                *size = Some((frame.width(), frame.height()));
+                if n > 0 {
+                    thread::sleep(delay);
+                }
+                out.write_all(ansi(frame, clear).as_bytes())?;
+                out.flush()
+            }
+            Sink::Images { dir, png } => {
+                let (width, height, pixels) = pixels(frame, scale);
+                if n == 0 {
+                    fs::create_dir_all(&*dir)?;
+                }
+                let name = format!("frame{:05}.{}", n, if *png { "png" } else { "ppm" });
+                let mut file = BufWriter::new(File::create(dir.join(name))?);
+
+                if *png {
+                    let mut encoder = png::Encoder::new(&mut file, width as u32, height as u32);
+                    encoder.set_color(png::ColorType::Rgb);
+                    encoder.set_depth(png::BitDepth::Eight);
+                    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+                    writer.write_image_data(&pixels).map_err(io::Error::other)?;
+                    writer.finish().map_err(io::Error::other)?;
+                } else {
+                    write!(file, "P6\n{} {}\n255\n", width, height)?;
+                    file.write_all(&pixels)?;
+                }
+                file.flush()
+            }
+            Sink::Gif { path, encoder } => {
+                let width = u16::try_from(frame.width() * scale);
+                let height = u16::try_from(frame.height() * scale);
+                let (Ok(width), Ok(height)) = (width, height) else {
+                    return Err(io::Error::other("the frames are too big for a gif"));
+                };
+
+                let encoder = match encoder {
+                    Some(encoder) => encoder,
+                    None => {
+                        let file = BufWriter::new(File::create(&*path)?);
+                        let mut new = gif::Encoder::new(file, width, height, &[])
+                            .map_err(io::Error::other)?;
+                        new.set_repeat(gif::Repeat::Infinite)
+                            .map_err(io::Error::other)?;
+                        encoder.insert(new)
+                    }
+                };
+
+                let mut frame = gif_frame(frame, scale);
+                frame.delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+                encoder.write_frame(&frame).map_err(io::Error::other)
+            }
+        }
+    }
+}
+
+// every tile as scale by scale pixels, as rgb bytes row by row
+fn pixels(frame: &Frame, scale: usize) -> (usize, usize, Vec<u8>) {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+
+    let mut res = Vec::with_capacity(width * height * 3);
+    for row in frame.rows() {
+        for _ in 0..scale {
+            for &Rgb(r, g, b) in row {
+                for _ in 0..scale {
+                    res.extend([r, g, b]);
+                }
+            }
+        }
+    }
+
+    (width, height, res)
+}
+
+// frames of a few colors, as simulations tend to have, get an exact palette
+// and the rest are left for the encoder to quantize
+fn gif_frame(frame: &Frame, scale: usize) -> gif::Frame<'static> {
+    let (width, height, rgb) = pixels(frame, scale);
+    let (width, height) = (width as u16, height as u16);
+
+    let mut palette = HashMap::new();
+    for &color in frame.cells() {
+        let k = palette.len();
+        palette.entry(color).or_insert(k);
+    }
+    if palette.len() > 256 {
+        return gif::Frame::from_rgb_speed(width, height, &rgb, 10);
+    }
+
+    let mut colors = vec![0; palette.len() * 3];
+    for (&Rgb(r, g, b), &k) in &palette {
+        colors[k * 3..k * 3 + 3].copy_from_slice(&[r, g, b]);
+    }
+    let indices = rgb
+        .chunks_exact(3)
+        .map(|c| palette[&Rgb(c[0], c[1], c[2])] as u8)
+        .collect::<Vec<_>>();
+
+    gif::Frame::from_palette_pixels(width, height, indices, colors, None)
+}
+
+// two rows of tiles per line of text, as the top and bottom halves of a block
+fn ansi(frame: &Frame, clear: bool) -> String {
+    let mut res = String::from(if clear { "\x1b[2J\x1b[H" } else { "\x1b[H" });
+
+    let rows = frame.rows().collect::<Vec<_>>();
+    for pair in rows.chunks(2) {
+        for j in 0..frame.width() {
+            let Rgb(r, g, b) = pair[0][j];
+            res.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+            match pair.get(1) {
+                Some(bottom) => {
+                    let Rgb(r, g, b) = bottom[j];
+                    res.push_str(&format!("\x1b[48;2;{};{};{}m\u{2580}", r, g, b));
+                }
+                None => res.push_str("\x1b[49m\u{2580}"),
+            }
+        }
+        res.push_str("\x1b[0m\n");
+    }
+
+    res
+}
@@ -0,0 +1,332 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// the cheapest way found from one of the starts to a goal, both included
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: usize,
+    pub states: Vec<S>,
+}
+
+impl<S> Path<S> {
+    pub fn start(&self) -> &S {
+        self.states.first().unwrap()
+    }
+
+    pub fn goal(&self) -> &S {
+        self.states.last().unwrap()
+    }
+}
+
+// every state seen so far, with the one it was first reached from
+struct Visited<S> {
+    states: Vec<(S, Option<usize>)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    // the index of state, if it had not been seen yet
+    fn insert(&mut self, state: S, parent: Option<usize>) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                let k = self.states.len();
+                self.states.push((entry.key().clone(), parent));
+                entry.insert(k);
+                Some(k)
+            }
+        }
+    }
+
+    // the states leading up to the k-th one, from its start on
+    fn path(&self, k: usize, cost: usize) -> Path<S> {
+        let mut states = Vec::new();
+        let mut at = Some(k);
+        while let Some(k) = at {
+            let (state, parent) = &self.states[k];
+            states.push(state.clone());
+            at = *parent;
+        }
+        states.reverse();
+        Path { cost, states }
+    }
+}
+
+// fewest steps from any of starts to a state that is a goal, or None if no
+// goal can be reached. the number of reachable states has to be finite
+pub fn bfs<S, I, N, It, G>(starts: I, mut next: N, mut goal: G) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> It,
+    It: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Some(k) = visited.insert(start, None) {
+            queue.push_back((k, 0));
+        }
+    }
+
+    while let Some((k, dist)) = queue.pop_front() {
+        let state = visited.states[k].0.clone();
+        if goal(&state) {
+            return Some(visited.path(k, dist));
+        }
+
+        for succ in next(&state) {
+            if let Some(succ) = visited.insert(succ, Some(k)) {
+                queue.push_back((succ, dist + 1));
+            }
+        }
+    }
+
+    None
+}
+
+// every state reachable from starts, in the order a bfs finds them
+pub fn reachable<S, I, N, It>(starts: I, mut next: N) -> Vec<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> It,
+    It: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+
+    for start in starts {
+        if seen.insert(start.clone()) {
+            order.push(start);
+        }
+    }
+
+    let mut k = 0;
+    while k < order.len() {
+        for succ in next(&order[k]) {
+            if seen.insert(succ.clone()) {
+                order.push(succ);
+            }
+        }
+        k += 1;
+    }
+
+    order
+}
+
+// cheapest path when every step has its own cost, next giving (state, cost) pairs
+pub fn dijkstra<S, I, N, It, G>(starts: I, next: N, goal: G) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> It,
+    It: IntoIterator<Item = (S, usize)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, next, |_| 0, goal)
+}
+
+// a state waiting in the heap, which pops the lowest estimate first
+struct Open<S> {
+    estimate: usize,
+    cost: usize,
+    state: S,
+}
+
+impl<S> PartialEq for Open<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S> Eq for Open<S> {}
+
+impl<S> PartialOrd for Open<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Open<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+// dijkstra guided by a heuristic of the cost left to reach a goal. as states
+// are never looked at again once done, it must be consistent for the path to
+// be the cheapest one: 0 at goals, and never dropping by more than a step
+// costs (h(s) <= cost + h(next)). never overestimating is not enough
+pub fn astar<S, I, N, It, H, G>(
+    starts: I,
+    mut next: N,
+    mut heuristic: H,
+    mut goal: G,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> It,
+    It: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    // the best known cost and the state it came from, by state
+    let mut best: HashMap<S, (usize, Option<S>)> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    let mut done = HashSet::new();
+
+    for start in starts {
+        heap.push(Open {
+            estimate: heuristic(&start),
+            cost: 0,
+            state: start.clone(),
+        });
+        best.insert(start, (0, None));
+    }
+
+    while let Some(Open { cost, state, .. }) = heap.pop() {
+        if best[&state].0 < cost || !done.insert(state.clone()) {
+            continue;
+        }
+
+        if goal(&state) {
+            let mut states = vec![state];
+            while let Some((_, Some(prev))) = best.get(states.last().unwrap()) {
+                states.push(prev.clone());
+            }
+            states.reverse();
+            return Some(Path { cost, states });
+        }
+
+        for (succ, step) in next(&state) {
+            let total = cost + step;
+            if best.get(&succ).is_none_or(|&(known, _)| total < known) {
+                heap.push(Open {
+                    estimate: total + heuristic(&succ),
+                    cost: total,
+                    state: succ.clone(),
+                });
+                best.insert(succ, (total, Some(state.clone())));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a 5x5 grid of (x, y) with a wall at x = 2 but for a gap at y = 4
+    fn open((x, y): (i32, i32)) -> bool {
+        (0..5).contains(&x) && (0..5).contains(&y) && (x != 2 || y == 4)
+    }
+
+    fn moves(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&p| open(p))
+            .collect()
+    }
+
+    // a path is made of single steps from its start to its goal
+    fn is_walk(path: &Path<(i32, i32)>) -> bool {
+        path.states.windows(2).all(|w| moves(&w[0]).contains(&w[1]))
+    }
+
+    #[test]
+    fn bfs_goes_around_the_wall() {
+        let path = bfs([(0, 0)], moves, |&p| p == (4, 0)).unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.states.len(), 13);
+        assert_eq!((*path.start(), *path.goal()), ((0, 0), (4, 0)));
+        assert!(is_walk(&path));
+    }
+
+    #[test]
+    fn bfs_from_the_closest_start() {
+        let path = bfs([(0, 0), (1, 4), (4, 4)], moves, |&p| p == (3, 3)).unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(*path.start(), (4, 4));
+
+        let path = bfs([(0, 0)], moves, |&p| p == (0, 0)).unwrap();
+        assert_eq!((path.cost, path.states), (0, vec![(0, 0)]));
+    }
+
+    #[test]
+    fn unreachable_goals() {
+        assert_eq!(bfs([(0, 0)], moves, |&p| p == (2, 0)), None);
+        let weighted = |p: &(i32, i32)| moves(p).into_iter().map(|q| (q, 1));
+        assert_eq!(dijkstra([(0, 0)], weighted, |&p| p == (2, 0)), None);
+        assert_eq!(dijkstra(Vec::<(i32, i32)>::new(), weighted, |_| true), None);
+    }
+
+    #[test]
+    fn reachable_in_bfs_order() {
+        let states = reachable([0, 0, 3], |&n: &i32| {
+            [n * 2, n + 1].into_iter().filter(|&m| m < 8)
+        });
+        assert_eq!(states, [0, 3, 1, 6, 4, 2, 7, 5]);
+
+        let left = reachable([(0, 0)], moves);
+        assert_eq!(left.len(), 21);
+        assert_eq!(left[0], (0, 0));
+    }
+
+    #[test]
+    fn dijkstra_weighs_steps() {
+        // a -> d directly costs 10, a -> b -> c -> d costs 3 in more steps
+        let edges = |&s: &char| -> Vec<(char, usize)> {
+            match s {
+                'a' => vec![('d', 10), ('b', 1)],
+                'b' => vec![('c', 1)],
+                'c' => vec![('d', 1)],
+                _ => vec![],
+            }
+        };
+
+        let path = dijkstra(['a'], edges, |&s| s == 'd').unwrap();
+        assert_eq!((path.cost, path.states), (3, vec!['a', 'b', 'c', 'd']));
+        assert_eq!(
+            bfs(['a'], |s| edges(s).into_iter().map(|e| e.0), |&s| s == 'd')
+                .unwrap()
+                .cost,
+            1
+        );
+
+        let path = dijkstra(['a', 'c'], edges, |&s| s == 'd').unwrap();
+        assert_eq!((path.cost, path.states), (1, vec!['c', 'd']));
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        // moving down costs 3, so the gap is expensive to get to
+        let weighted = |&(x, y): &(i32, i32)| {
+            moves(&(x, y))
+                .into_iter()
+                .map(move |q| (q, if q.1 > y { 3 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let manhattan = |&(x, y): &(i32, i32)| ((4 - x).abs() + y.abs()) as usize;
+
+        let slow = dijkstra([(0, 0)], weighted, |&p| p == (4, 0)).unwrap();
+        let fast = astar([(0, 0)], weighted, manhattan, |&p| p == (4, 0)).unwrap();
+        assert_eq!(slow.cost, 4 * 3 + 4 + 4);
+        assert_eq!(fast.cost, slow.cost);
+        assert!(is_walk(&fast));
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::Parser;
+
+use crate::input::InputArgs;
+use crate::output::{self, Format};
+use crate::profile::{self, Phase};
+use crate::random::Rng;
+use crate::render::Recorder;
+use crate::step::Simulation;
+use crate::ParseError;
+
+pub trait Solution {
+    const DAY: u32;
+    // how many parts the day has, which part2 has to agree with
+    const PARTS: u32 = 2;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> String;
+
+    // None for the days with a single part
+    fn part2(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    // a random input that parses and has answers, growing with size in
+    // whatever unit suits the day (rucksacks, numbers, side of the map...)
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    // records a frame for every step of the simulation, or returns false for
+    // the days that have none to show
+    fn visualize(_input: &Self::Input, _rec: &mut Recorder) -> bool {
+        false
+    }
+
+    // what a part runs a step at a time, for the days that simulate something
+    fn simulate(_input: &Self::Input, _part: u32) -> Option<Box<dyn Simulation + '_>> {
+        None
+    }
+}
+
+pub struct Answer {
+    pub part: u32,
+    pub value: String,
+    // how long solving the part took, not counting the parse
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // multi line answers (like the day 10 crt) start on their own line
+        if self.value.contains('\n') {
+            write!(f, "{}:\n{}", self.part, self.value)
+        } else {
+            write!(f, "{}: {}", self.part, self.value)
+        }
+    }
+}
+
+// object safe view of a Solution, so the runner can keep all days in one table
+pub trait Day: Sync {
+    fn day(&self) -> u32;
+    fn parts(&self) -> u32;
+    fn solve(&self, input: &str, part: Option<u32>) -> Result<Vec<Answer>, ParseError>;
+    fn profile(&self, input: &str, part: Option<u32>) -> Result<Profiled, ParseError>;
+    fn generate(&self, size: usize, seed: u64) -> String;
+    // false if the day has nothing to animate
+    fn visualize(&self, input: &str, rec: &mut Recorder) -> Result<bool, ParseError>;
+    // hands the simulation of a part to debug, or returns false if there is none
+    fn step(
+        &self,
+        input: &str,
+        part: u32,
+        debug: &mut dyn FnMut(&mut dyn Simulation),
+    ) -> Result<bool, ParseError>;
+}
+
+impl<S: Solution + Sync> Day for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn parts(&self) -> u32 {
+        S::PARTS
+    }
+
+    fn solve(&self, input: &str, part: Option<u32>) -> Result<Vec<Answer>, ParseError> {
+        solve::<S>(input, part)
+    }
+
+    fn profile(&self, input: &str, part: Option<u32>) -> Result<Profiled, ParseError> {
+        profile::<S>(input, part)
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> String {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn visualize(&self, input: &str, rec: &mut Recorder) -> Result<bool, ParseError> {
+        let parsed = S::parse(input).map_err(|err| err.in_day(S::DAY))?;
+        Ok(S::visualize(&parsed, rec))
+    }
+
+    fn step(
+        &self,
+        input: &str,
+        part: u32,
+        debug: &mut dyn FnMut(&mut dyn Simulation),
+    ) -> Result<bool, ParseError> {
+        let parsed = S::parse(input).map_err(|err| err.in_day(S::DAY))?;
+        let Some(mut sim) = S::simulate(&parsed, part) else {
+            return Ok(false);
+        };
+        debug(sim.as_mut());
+        Ok(true)
+    }
+}
+
+// the answers of a day along with what the parse and every part took
+pub struct Profiled {
+    pub answers: Vec<Answer>,
+    pub phases: Vec<Phase>,
+}
+
+pub fn profile<S: Solution>(input: &str, part: Option<u32>) -> Result<Profiled, ParseError> {
+    let (parsed, phase) = profile::measure("parse", || S::parse(input));
+    let parsed = parsed.map_err(|err| err.in_day(S::DAY))?;
+    let mut phases = vec![phase];
+
+    let answers = (1..=S::PARTS)
+        .filter(|&p| part.is_none_or(|part| part == p))
+        .filter_map(|part| {
+            let (value, phase) = profile::measure(format!("part {}", part), || match part {
+                1 => Some(S::part1(&parsed)),
+                _ => S::part2(&parsed),
+            });
+            let value = value?;
+            let elapsed = phase.elapsed;
+            phases.push(phase);
+
+            Some(Answer {
+                part,
+                value,
+                elapsed,
+            })
+        })
+        .collect();
+
+    Ok(Profiled { answers, phases })
+}
+
+pub fn solve<S: Solution>(input: &str, part: Option<u32>) -> Result<Vec<Answer>, ParseError> {
+    profile::<S>(input, part).map(|profiled| profiled.answers)
+}
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+// entry point for the per day binaries, which look for their input in the current directory
+pub fn main<S: Solution>() -> ExitCode {
+    let cli = Cli::parse();
+    run::<S>(&cli.input, cli.format)
+}
+
+// what main does once the arguments are parsed, for binaries with a few more of them
+pub fn run<S: Solution>(input: &InputArgs, format: Format) -> ExitCode {
+    match load(input, |content| solve::<S>(content, None)) {
+        Ok(answers) => {
+            output::print(S::DAY, &answers, format);
+            ExitCode::SUCCESS
+        }
+        Err(code) => code,
+    }
+}
+
+// reads the input of a per day binary and parses it, telling on stderr what
+// went wrong otherwise
+pub fn load<T, P>(input: &InputArgs, parse: P) -> Result<T, ExitCode>
+where
+    P: FnOnce(&str) -> Result<T, ParseError>,
+{
+    let source = input.source();
+
+    let content = source.read(Path::new("")).map_err(|err| {
+        eprintln!("error: could not read {}: {}", source.describe(Path::new("")), err);
+        ExitCode::FAILURE
+    })?;
+
+    parse(&content).map_err(|err| {
+        eprintln!("error: {}", err.report(&content));
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,201 @@
+use std::io::{self, BufRead, Write};
+
+// a simulation that can be run a step at a time, such as the rope of day 9
+// moving a tile or the monkeys of day 11 playing a round
+pub trait Simulation {
+    // advances by a step, or returns false if there are none left
+    fn step(&mut self) -> bool;
+    // named counters, to show and to break on
+    fn counters(&self) -> Vec<(String, i64)>;
+    fn render(&self) -> String;
+}
+
+const HELP: &str = "\
+step [N]        advance N steps, 1 by default (so does an empty line)
+continue        run until a breakpoint holds or the simulation is over
+print           show the state
+info            show the counters
+break C OP N    stop once counter C compares to N, with OP one of == != < <= > >=
+delete [K]      remove breakpoint K, or all of them
+breakpoints     list the breakpoints
+help            show this
+quit            leave";
+
+const OPS: [&str; 6] = ["==", "!=", "<=", ">=", "<", ">"];
+
+// a condition like `round == 10`
+struct Breakpoint {
+    counter: String,
+    op: &'static str,
+    value: i64,
+}
+
+impl Breakpoint {
+    fn parse(args: &[&str], sim: &dyn Simulation) -> Result<Self, String> {
+        let [counter, op, value] = args else {
+            return Err("usage: break COUNTER OP VALUE, as in `break round == 10`".to_string());
+        };
+
+        let counters = sim.counters();
+        if !counters.iter().any(|(name, _)| name == counter) {
+            let names = counters
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>();
+            return Err(format!(
+                "no counter {}, there are {}",
+                counter,
+                names.join(", ")
+            ));
+        }
+        let op = OPS
+            .into_iter()
+            .find(|o| o == op)
+            .ok_or_else(|| format!("no operator {}, there are {}", op, OPS.join(" ")))?;
+        let value = value
+            .parse()
+            .map_err(|_| format!("{} is not a number", value))?;
+
+        Ok(Self {
+            counter: counter.to_string(),
+            op,
+            value,
+        })
+    }
+
+    fn holds(&self, counters: &[(String, i64)]) -> bool {
+        let Some(&(_, x)) = counters.iter().find(|(name, _)| *name == self.counter) else {
+            return false;
+        };
+
+        match self.op {
+            "==" => x == self.value,
+            "!=" => x != self.value,
+            "<=" => x <= self.value,
+            ">=" => x >= self.value,
+            "<" => x < self.value,
+            _ => x > self.value,
+        }
+    }
+}
+
+fn show(counters: &[(String, i64)]) -> String {
+    let counters = counters
+        .iter()
+        .map(|(name, x)| format!("{}={}", name, x))
+        .collect::<Vec<_>>();
+    counters.join(" ")
+}
+
+struct Repl<'a, W> {
+    sim: &'a mut dyn Simulation,
+    out: W,
+    breakpoints: Vec<Breakpoint>,
+    over: bool,
+}
+
+impl<W: Write> Repl<'_, W> {
+    // up to n steps, stopping early at a breakpoint
+    fn run(&mut self, n: Option<usize>) -> io::Result<()> {
+        if self.over {
+            return writeln!(self.out, "the simulation is over");
+        }
+
+        let mut taken = 0;
+        while n.is_none_or(|n| taken < n) {
+            if !self.sim.step() {
+                self.over = true;
+                writeln!(self.out, "the simulation is over")?;
+                break;
+            }
+            taken += 1;
+
+            let counters = self.sim.counters();
+            let hit = self.breakpoints.iter().position(|b| b.holds(&counters));
+            if let Some(k) = hit {
+                let b = &self.breakpoints[k];
+                writeln!(
+                    self.out,
+                    "breakpoint {}: {} {} {}",
+                    k + 1,
+                    b.counter,
+                    b.op,
+                    b.value
+                )?;
+                break;
+            }
+        }
+
+        writeln!(self.out, "{}", show(&self.sim.counters()))
+    }
+
+    // false once it is time to leave
+    fn command(&mut self, line: &str) -> io::Result<bool> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let (command, args) = match words.split_first() {
+            Some((command, args)) => (*command, args),
+            None => ("step", &[][..]),
+        };
+
+        match (command, args) {
+            ("step" | "s", []) => self.run(Some(1))?,
+            ("step" | "s", [n]) => match n.parse() {
+                Ok(n) => self.run(Some(n))?,
+                Err(_) => writeln!(self.out, "{} is not a number of steps", n)?,
+            },
+            ("continue" | "c", []) => self.run(None)?,
+            ("print" | "p", []) => write!(self.out, "{}", self.sim.render())?,
+            ("info" | "i", []) => writeln!(self.out, "{}", show(&self.sim.counters()))?,
+            ("break" | "b", args) => match Breakpoint::parse(args, self.sim) {
+                Ok(b) => {
+                    self.breakpoints.push(b);
+                    writeln!(self.out, "breakpoint {} set", self.breakpoints.len())?;
+                }
+                Err(err) => writeln!(self.out, "{}", err)?,
+            },
+            ("delete" | "d", []) => self.breakpoints.clear(),
+            ("delete" | "d", [k]) => match k.parse::<usize>() {
+                Ok(k) if (1..=self.breakpoints.len()).contains(&k) => {
+                    self.breakpoints.remove(k - 1);
+                }
+                _ => writeln!(self.out, "no breakpoint {}", k)?,
+            },
+            ("breakpoints", []) => {
+                for (k, b) in self.breakpoints.iter().enumerate() {
+                    writeln!(self.out, "{}: {} {} {}", k + 1, b.counter, b.op, b.value)?;
+                }
+            }
+            ("help" | "h", []) => writeln!(self.out, "{}", HELP)?,
+            ("quit" | "q", []) => return Ok(false),
+            _ => writeln!(self.out, "unknown command `{}`, try help", line.trim())?,
+        }
+
+        Ok(true)
+    }
+}
+
+// reads commands from input until quit or the end of it, answering on out
+pub fn repl(sim: &mut dyn Simulation, input: impl BufRead, out: impl Write) -> io::Result<()> {
+    let mut repl = Repl {
+        sim,
+        out,
+        breakpoints: Vec::new(),
+        over: false,
+    };
+
+    writeln!(repl.out, "{}", show(&repl.sim.counters()))?;
+    let mut lines = input.lines();
+    loop {
+        write!(repl.out, "> ")?;
+        repl.out.flush()?;
+
+        let Some(line) = lines.next() else {
+            break;
+        };
+        if !repl.command(&line?)? {
+            break;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+
+use aoc_common::parse;
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut elves = Vec::new();
+
+        for group in parse::groups(input) {
+            let mut total = 0u64;
+            for mut s in group {
+                let start = s.clone();
+                let calories = s.number::<u64>()?;
+                s.finish()?;
+                total = total
+                    .checked_add(calories)
+                    .ok_or_else(|| start.error("calories that fit in a total"))?;
+            }
+            elves.push(total);
+        }
+
+        if elves.is_empty() {
+            return Err(parse::missing(input, "the calories of an elf"));
+        }
+
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input) -> String {
+        top_k(elves, 1)[0].1.to_string()
+    }
+
+    // every total fits in a u64, but three of them together might not
+    fn part2(elves: &Self::Input) -> Option<String> {
+        Some(
+            top_k(elves, 3)
+                .into_iter()
+                .map(|(_, calories)| u128::from(calories))
+                .sum::<u128>()
+                .to_string(),
+        )
+    }
+
+    // size elves, carrying a few snacks each
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(3))
+            .map(|_| {
+                let snacks = rng.range(1, 6);
+                (0..snacks)
+                    .map(|_| format!("{}\n", rng.range(1000, 20000)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+// the k elves carrying the most, as their index in the input and their total,
+// the most first and the earliest first on ties
+pub fn top_k(elves: &[u64], k: usize) -> Vec<(usize, u64)> {
+    // the smallest of the best k so far sits on top, ready to be replaced
+    let mut heap = BinaryHeap::with_capacity(k.min(elves.len()) + 1);
+    for (i, &calories) in elves.iter().enumerate() {
+        heap.push(Reverse((calories, Reverse(i))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((calories, Reverse(i)))| (i, calories))
+        .collect()
+}
+
+// how the totals of the elves are spread
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub mean: f64,
+    pub median: f64,
+    // buckets of equal width from the smallest total to the largest, as
+    // the range of totals they hold and how many elves fall in it
+    pub histogram: Vec<(u64, u64, usize)>,
+}
+
+impl Stats {
+    pub fn new(elves: &[u64], buckets: usize) -> Self {
+        let mut sorted = elves.to_vec();
+        sorted.sort_unstable();
+
+        let count = sorted.len();
+        let mean = sorted.iter().map(|&c| c as f64).sum::<f64>() / count as f64;
+        let median = match count {
+            0 => f64::NAN,
+            _ if count % 2 == 1 => sorted[count / 2] as f64,
+            _ => (sorted[count / 2 - 1] as f64 + sorted[count / 2] as f64) / 2.0,
+        };
+
+        let histogram = match (sorted.first(), sorted.last()) {
+            (Some(&lo), Some(&hi)) => {
+                let width = ((hi - lo) / buckets.max(1) as u64 + 1).max(1);
+                let mut histogram = (0..)
+                    .map(|k| (lo + k * width, lo + (k + 1) * width - 1, 0))
+                    .take_while(|&(from, _, _)| from <= hi)
+                    .collect::<Vec<_>>();
+                for &calories in &sorted {
+                    histogram[((calories - lo) / width) as usize].2 += 1;
+                }
+                histogram
+            }
+            _ => Vec::new(),
+        };
+
+        Self {
+            count,
+            mean,
+            median,
+            histogram,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "elves: {}", self.count)?;
+        writeln!(f, "mean: {:.1}", self.mean)?;
+        writeln!(f, "median: {:.1}", self.median)?;
+
+        // the fullest bucket gets a bar of 40
+        let most = self.histogram.iter().map(|b| b.2).max().unwrap_or(0);
+        let width = self
+            .histogram
+            .iter()
+            .map(|(from, to, _)| format!("{}..={}", from, to).len())
+            .max()
+            .unwrap_or(0);
+        for &(from, to, n) in &self.histogram {
+            let bar = "#".repeat((n * 40).div_ceil(most));
+            let range = format!("{}..={}", from, to);
+            writeln!(f, "{:>width$} {:>5} {}", range, n, bar, width = width)?;
+        }
+
+        Ok(())
+    }
+}
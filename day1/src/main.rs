@@ -1,49 +1,52 @@
-use std::fs;
-use std::io::{self, BufRead};
-
-fn line_reader<F>(mut f: F) -> io::Result<()>
-where
-    F: FnMut(&String) -> (),
-{
-    let input = fs::File::open("./input")?;
-    let mut reader = io::BufReader::new(input);
-
-    let mut line = String::new();
-    while 0 < reader.read_line(&mut line)? {
-        f(&line);
-        line.clear();
-    }
-
-    Ok(())
+use std::process::ExitCode;
+
+use aoc_common::input::InputArgs;
+use aoc_common::output::Format;
+use aoc_common::{solution, Solution};
+use clap::Parser;
+use day1::{Day1, Stats};
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Report how the calories are spread among the elves instead of the answers
+    #[arg(long)]
+    stats: bool,
+
+    /// List the K elves carrying the most instead of the answers
+    #[arg(long, value_name = "K")]
+    top: Option<usize>,
+
+    /// Buckets of the histogram of the stats
+    #[arg(long, value_name = "N", default_value_t = 10, requires = "stats")]
+    buckets: usize,
 }
 
-fn main() -> io::Result<()> {
-    let mut elves = Vec::new();
-    let mut acc = 0u64;
-
-    line_reader(|line| match line.len() > 1 {
-        true => {
-            acc += line[..line.len() - 1].parse::<u64>().unwrap();
-        }
-        false => {
-            elves.push(acc);
-            acc = 0;
-        }
-    })?;
-
-    println!("1: {}", elves.iter().max().unwrap());
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if !cli.stats && cli.top.is_none() {
+        return solution::run::<Day1>(&cli.input, cli.format);
+    }
 
-    let mut top3 = vec![elves[0], elves[1], elves[2]];
-    top3.sort();
+    let elves = match solution::load(&cli.input, Day1::parse) {
+        Ok(elves) => elves,
+        Err(code) => return code,
+    };
 
-    elves[3..].into_iter().for_each(|&elf| {
-        if elf > top3[0] {
-            top3[0] = elf;
-            top3.sort();
+    if cli.stats {
+        print!("{}", Stats::new(&elves, cli.buckets));
+    }
+    if let Some(k) = cli.top {
+        for (i, calories) in day1::top_k(&elves, k) {
+            println!("elf {}: {}", i + 1, calories);
         }
-    });
-
-    println!("2: {}", top3.into_iter().sum::<u64>());
+    }
 
-    Ok(())
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day1::Day1;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day1::parse(EXAMPLE).unwrap();
+    assert_eq!(Day1::part1(&input), "24000");
+}
+
+#[test]
+fn part2() {
+    let input = Day1::parse(EXAMPLE).unwrap();
+    assert_eq!(Day1::part2(&input).unwrap(), "45000");
+}
+
+#[test]
+fn totals_too_big_to_add() {
+    let input = Day1::parse("18446744073709551615\n\n1\n\n1\n").unwrap();
+    assert_eq!(Day1::part2(&input).unwrap(), "18446744073709551617");
+}
@@ -0,0 +1,34 @@
+use aoc_common::Solution;
+use day1::Day1;
+
+#[test]
+fn last_elf_needs_no_blank_line() {
+    assert_eq!(Day1::parse("1\n2\n\n3").unwrap(), [3, 3]);
+    assert_eq!(Day1::parse("1\n2\n\n3\n").unwrap(), [3, 3]);
+}
+
+#[test]
+fn crlf_line_endings() {
+    assert_eq!(Day1::parse("1\r\n2\r\n\r\n3\r\n").unwrap(), [3, 3]);
+}
+
+#[test]
+fn runs_of_blank_lines_are_one_separator() {
+    assert_eq!(Day1::parse("\n\n1\n\n\n\n2\n\n\n").unwrap(), [1, 2]);
+}
+
+#[test]
+fn bad_lines_are_reported_where_they_are() {
+    let err = Day1::parse("1000\n2000\n\n3000\nabc\n").unwrap_err();
+    assert_eq!((err.line, err.column), (5, 1));
+    assert_eq!(err.expected, "a number");
+
+    let err = Day1::parse("1000\n2000 \n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 5));
+}
+
+#[test]
+fn no_elves_is_an_error() {
+    assert!(Day1::parse("").is_err());
+    assert!(Day1::parse("\n\n").is_err());
+}
@@ -0,0 +1,64 @@
+use std::process::Command;
+
+use aoc_common::Solution;
+use day1::{top_k, Day1, Stats};
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn heaviest_elves_first() {
+    let elves = Day1::parse(EXAMPLE).unwrap();
+    assert_eq!(top_k(&elves, 3), [(3, 24000), (2, 11000), (4, 10000)]);
+    assert_eq!(top_k(&elves, 0), []);
+    assert_eq!(top_k(&elves, 9).len(), 5);
+}
+
+#[test]
+fn more_elves_asked_for_than_there_are() {
+    let elves = Day1::parse(EXAMPLE).unwrap();
+    assert_eq!(top_k(&elves, 100_000_000_000).len(), 5);
+    assert_eq!(top_k(&elves, usize::MAX)[0], (3, 24000));
+}
+
+#[test]
+fn ties_go_to_the_earliest() {
+    assert_eq!(top_k(&[5, 7, 5, 7, 5], 3), [(1, 7), (3, 7), (0, 5)]);
+}
+
+#[test]
+fn stats_of_the_example() {
+    let elves = Day1::parse(EXAMPLE).unwrap();
+    let stats = Stats::new(&elves, 4);
+    assert_eq!(stats.count, 5);
+    assert_eq!(stats.mean, 11000.0);
+    assert_eq!(stats.median, 10000.0);
+    assert_eq!(
+        stats.histogram,
+        [
+            (4000, 9000, 2),
+            (9001, 14001, 2),
+            (14002, 19002, 0),
+            (19003, 24003, 1)
+        ]
+    );
+
+    assert_eq!(Stats::new(&[1, 2, 3, 4], 1).median, 2.5);
+    assert_eq!(Stats::new(&[7, 7], 10).histogram, [(7, 7, 2)]);
+}
+
+#[test]
+fn binary_reports_stats() {
+    let out = Command::new(env!("CARGO_BIN_EXE_day1"))
+        .args(["--example", "--stats", "--top", "1"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(out.status.success());
+    assert!(
+        stdout.starts_with("elves: 5\nmean: 11000.0\nmedian: 10000.0\n"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.ends_with("elf 4: 24000\n"), "{}", stdout);
+}
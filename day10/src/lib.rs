@@ -0,0 +1,165 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::step::Simulation;
+use aoc_common::{ParseError, Solution};
+
+#[derive(Clone)]
+pub enum Ins {
+    Nop,
+    Add(i32, i32),
+}
+
+// the most an addx can change x by, which keeps x well within an i64
+const MAX_ADD: i32 = 1_000_000;
+
+// the cycle of the last signal strength the puzzle asks for
+const LAST_SIGNAL: i64 = 220;
+
+fn parse_ins(s: &mut Scanner) -> parse::Result<Ins> {
+    match s.choice(&["noop", "addx "])? {
+        "noop" => Ok(Ins::Nop),
+        _ => Ok(Ins::Add(s.number_in(-MAX_ADD, MAX_ADD)?, 2)),
+    }
+}
+
+struct Cpu {
+    reg: i64,
+    pc: usize,
+    ins: Vec<Ins>,
+    // the cycle about to start, the signal strengths so far and the crt drawn so far
+    clock: i64,
+    signal: i64,
+    crt: String,
+}
+
+const FILL: char = '█';
+const EMPT: char = ' ';
+
+impl Cpu {
+    fn new(ins: Vec<Ins>) -> Self {
+        Self {
+            reg: 1,
+            pc: 0,
+            ins,
+            clock: 1,
+            signal: 0,
+            crt: String::new(),
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.pc >= self.ins.len()
+    }
+
+    // one cycle
+    fn tick(&mut self) {
+        if self.clock % 40 - 20 == 0 && self.clock <= LAST_SIGNAL {
+            self.signal += self.reg * self.clock;
+        }
+
+        let pixel = (self.clock - 1) % 40;
+        if pixel == 0 && self.clock > 1 {
+            self.crt.push('\n');
+        }
+
+        if self.reg - 1 <= pixel && pixel <= self.reg + 1 {
+            self.crt.push(FILL);
+        } else {
+            self.crt.push(EMPT);
+        }
+
+        match self.ins.get_mut(self.pc).unwrap() {
+            Ins::Nop => self.pc += 1,
+            Ins::Add(x, 1) => {
+                self.reg += i64::from(*x);
+                self.pc += 1;
+            }
+            Ins::Add(_, c) => *c -= 1,
+        }
+
+        self.clock += 1;
+    }
+
+    fn run(&mut self) -> (i64, String) {
+        while !self.is_done() {
+            self.tick();
+        }
+
+        (self.signal, self.crt.clone())
+    }
+}
+
+impl Simulation for Cpu {
+    fn step(&mut self) -> bool {
+        if self.is_done() {
+            return false;
+        }
+        self.tick();
+        true
+    }
+
+    // the cycles done, with x as it is during the next one
+    fn counters(&self) -> Vec<(String, i64)> {
+        [
+            ("cycle", self.clock - 1),
+            ("x", self.reg),
+            ("signal", self.signal),
+            ("pc", self.pc as i64),
+        ]
+        .map(|(name, x)| (name.to_string(), x))
+        .to_vec()
+    }
+
+    fn render(&self) -> String {
+        self.crt.clone() + "\n"
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Vec<Ins>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, parse_ins)
+    }
+
+    fn part1(instructions: &Self::Input) -> String {
+        Cpu::new(instructions.clone()).run().0.to_string()
+    }
+
+    fn part2(instructions: &Self::Input) -> Option<String> {
+        Some(Cpu::new(instructions.clone()).run().1)
+    }
+
+    // both parts run the same program
+    fn simulate(instructions: &Self::Input, _part: u32) -> Option<Box<dyn Simulation + '_>> {
+        Some(Box::new(Cpu::new(instructions.clone())))
+    }
+
+    // size instructions, or however many more it takes to draw the whole crt,
+    // keeping the sprite around the screen
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let (mut res, mut reg, mut cycles) = (String::new(), 1, 0);
+
+        for i in 0.. {
+            if i >= size && cycles >= 240 {
+                break;
+            }
+
+            if rng.chance(0.3) {
+                res.push_str("noop\n");
+                cycles += 1;
+            } else {
+                let x = rng.range(-10 - reg, 50 - reg).clamp(-20, 20);
+                reg += x;
+                res.push_str(&format!("addx {}\n", x));
+                cycles += 2;
+            }
+        }
+
+        res
+    }
+}
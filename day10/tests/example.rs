@@ -0,0 +1,36 @@
+use aoc_common::Solution;
+use day10::Day10;
+
+const EXAMPLE: &str = include_str!("../example");
+
+const CRT: &str = "\
+██  ██  ██  ██  ██  ██  ██  ██  ██  ██  \n\
+███   ███   ███   ███   ███   ███   ███ \n\
+████    ████    ████    ████    ████    \n\
+█████     █████     █████     █████     \n\
+██████      ██████      ██████      ████\n\
+███████       ███████       ███████     ";
+
+#[test]
+fn part1() {
+    let input = Day10::parse(EXAMPLE).unwrap();
+    assert_eq!(Day10::part1(&input), "13140");
+}
+
+#[test]
+fn part2() {
+    let input = Day10::parse(EXAMPLE).unwrap();
+    assert_eq!(Day10::part2(&input).unwrap(), CRT);
+}
+
+#[test]
+fn large_adds_past_the_last_signal() {
+    let input = Day10::parse(&"addx 1000000\n".repeat(3000)).unwrap();
+    assert_eq!(Day10::part1(&input), "56480000720");
+}
+
+#[test]
+fn adds_too_large() {
+    let err = Day10::parse("noop\naddx -1000001\n").err().unwrap();
+    assert_eq!((err.line, err.column), (2, 6));
+}
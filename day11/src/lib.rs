@@ -0,0 +1,322 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::step::Simulation;
+use aoc_common::{ParseError, Solution};
+
+// the largest worry level that can still be squared in an i64, which bounds
+// the starting items, the operands and the common multiple of the tests
+const MAX_WORRY: i64 = 3_037_000_499;
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<i64>,
+    expr: Expr,
+    test: i64,
+    true_branch: i32,
+    false_branch: i32,
+    inspects: i64,
+}
+
+#[derive(Debug, Clone)]
+struct Expr {
+    op: char,
+    operand1: Operand,
+    operand2: Operand,
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Old,
+    Int(i64),
+}
+
+impl Expr {
+    fn execute(&self, old: i64) -> i64 {
+        let op1 = match self.operand1 {
+            Operand::Old => old,
+            Operand::Int(x) => x,
+        };
+
+        let op2 = match self.operand2 {
+            Operand::Old => old,
+            Operand::Int(x) => x,
+        };
+
+        match self.op {
+            '+' => op1 + op2,
+            '*' => op1 * op2,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Monkey {
+    fn throw(&mut self, relief: i64, modulo: i64) -> Vec<(i32, i64)> {
+        self.items
+            .drain(..)
+            .map(|item| {
+                self.inspects += 1;
+                let new = (self.expr.execute(item) / relief) % modulo;
+                let next = if new % self.test == 0 {
+                    self.true_branch
+                } else {
+                    self.false_branch
+                };
+                (next, new)
+            })
+            .collect()
+    }
+}
+
+// modulo is the common multiple of the tests so far, and has to stay at most
+// MAX_WORRY so that worry levels can be kept below it
+fn parse_monkey(s: &mut Scanner, id: i32, count: i32, modulo: &mut i64) -> parse::Result<Monkey> {
+    s.expect("Monkey ")?;
+    s.number_in(id, id)?;
+    s.expect(":\n  Starting items: ")?;
+
+    let mut items = Vec::new();
+    if s.peek() != Some('\n') {
+        items.push(s.number_in(0, MAX_WORRY)?);
+        while s.eat(", ") {
+            items.push(s.number_in(0, MAX_WORRY)?);
+        }
+    }
+
+    s.expect("\n  Operation: new = ")?;
+    let expr = parse_expr(s)?;
+    s.expect("\n  Test: divisible by ")?;
+    let start = s.clone();
+    let test = s.number_in(1, MAX_WORRY)?;
+    *modulo = lcm(*modulo, test)
+        .filter(|&m| m <= MAX_WORRY)
+        .ok_or_else(|| {
+            start.error(format!(
+                "tests with a common multiple of at most {}",
+                MAX_WORRY
+            ))
+        })?;
+    s.expect("\n    If true: throw to monkey ")?;
+    let true_branch = s.number_in(0, count - 1)?;
+    s.expect("\n    If false: throw to monkey ")?;
+    let false_branch = s.number_in(0, count - 1)?;
+
+    Ok(Monkey {
+        items,
+        expr,
+        test,
+        true_branch,
+        false_branch,
+        inspects: 0,
+    })
+}
+
+fn parse_operand(s: &mut Scanner) -> parse::Result<Operand> {
+    if s.eat("old") {
+        Ok(Operand::Old)
+    } else {
+        Ok(Operand::Int(s.number_in(0, MAX_WORRY)?))
+    }
+}
+
+fn parse_expr(s: &mut Scanner) -> parse::Result<Expr> {
+    let operand1 = parse_operand(s)?;
+    let op = match s.choice(&[" + ", " * "])? {
+        " + " => '+',
+        _ => '*',
+    };
+    let operand2 = parse_operand(s)?;
+
+    Ok(Expr {
+        op,
+        operand1,
+        operand2,
+    })
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if a == 0 {
+        return b;
+    }
+
+    if b == 0 {
+        return a;
+    }
+
+    match a.cmp(&b) {
+        std::cmp::Ordering::Equal => a,
+        std::cmp::Ordering::Less => gcd(a, b % a),
+        std::cmp::Ordering::Greater => gcd(b, a % b),
+    }
+}
+
+fn lcm(a: i64, b: i64) -> Option<i64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// the monkeys playing keep away, a round at a time
+struct Troop {
+    monkeys: Vec<Monkey>,
+    relief: i64,
+    modulo: i64,
+    round: usize,
+    rounds: usize,
+}
+
+impl Troop {
+    fn new(monkeys: &[Monkey], rounds: usize, relief: i64) -> Self {
+        let modulo: i64 = monkeys.iter().map(|m| m.test).try_fold(1, lcm).unwrap();
+
+        Self {
+            monkeys: monkeys.to_vec(),
+            relief,
+            modulo,
+            round: 0,
+            rounds,
+        }
+    }
+
+    fn play_round(&mut self) {
+        for i in 0..self.monkeys.len() {
+            for (dest, val) in self.monkeys[i].throw(self.relief, self.modulo) {
+                self.monkeys[dest as usize].items.push(val);
+            }
+        }
+        self.round += 1;
+    }
+
+    fn business(&self) -> i64 {
+        let mut inspects = self.monkeys.iter().map(|m| m.inspects).collect::<Vec<_>>();
+        inspects.sort();
+
+        inspects[inspects.len() - 1] * inspects[inspects.len() - 2]
+    }
+}
+
+impl Simulation for Troop {
+    fn step(&mut self) -> bool {
+        if self.round == self.rounds {
+            return false;
+        }
+        self.play_round();
+        true
+    }
+
+    // with how many items every monkey inspected so far
+    fn counters(&self) -> Vec<(String, i64)> {
+        let mut res = vec![
+            ("round".to_string(), self.round as i64),
+            ("business".to_string(), self.business()),
+        ];
+        for (i, monkey) in self.monkeys.iter().enumerate() {
+            res.push((format!("monkey{}", i), monkey.inspects));
+        }
+        res
+    }
+
+    fn render(&self) -> String {
+        let mut res = String::new();
+        for (i, monkey) in self.monkeys.iter().enumerate() {
+            let items = monkey.items.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+            res.push_str(format!("Monkey {}: {}", i, items.join(", ")).trim_end());
+            res.push('\n');
+        }
+        res
+    }
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: i64) -> i64 {
+    let mut troop = Troop::new(monkeys, rounds, relief);
+    while troop.step() {}
+    troop.business()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let count = input.matches("Monkey ").count() as i32;
+        let mut s = Scanner::new(input);
+
+        // monkey business is the product of the two most active monkeys
+        if count < 2 {
+            return Err(parse::missing(input, "at least two monkeys"));
+        }
+
+        let (mut monkeys, mut modulo) = (Vec::new(), 1);
+        for id in 0..count {
+            if id > 0 {
+                s.expect("\n\n")?;
+            }
+            monkeys.push(parse_monkey(&mut s, id, count, &mut modulo)?);
+        }
+
+        s.eat("\n");
+        s.finish()?;
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> String {
+        monkey_business(monkeys, 20, 3).to_string()
+    }
+
+    fn part2(monkeys: &Self::Input) -> Option<String> {
+        Some(monkey_business(monkeys, 10000, 1).to_string())
+    }
+
+    fn simulate(monkeys: &Self::Input, part: u32) -> Option<Box<dyn Simulation + '_>> {
+        Some(Box::new(match part {
+            1 => Troop::new(monkeys, 20, 3),
+            _ => Troop::new(monkeys, 10000, 1),
+        }))
+    }
+
+    // about size items, spread over up to 9 monkeys. every monkey tests a
+    // different prime, which keeps their product small enough to square
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let count = (size / 5).clamp(2, 9);
+        let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut primes);
+        let squarer = rng.index(count);
+
+        (0..count)
+            .map(|id| {
+                let items = (0..rng.range(1, (size / count).max(1) as i64 * 2))
+                    .map(|_| rng.range(50, 99).to_string())
+                    .collect::<Vec<_>>();
+
+                let operation = if id == squarer {
+                    "old * old".to_string()
+                } else if rng.chance(0.5) {
+                    format!("old + {}", rng.range(1, 8))
+                } else {
+                    format!("old * {}", rng.range(2, 19))
+                };
+
+                // throws always go to two other monkeys
+                let others = (0..count).filter(|&other| other != id).collect::<Vec<_>>();
+                let yes = *rng.pick(&others);
+                let no = match others.iter().filter(|&&other| other != yes).collect::<Vec<_>>() {
+                    rest if rest.is_empty() => yes,
+                    rest => **rng.pick(&rest),
+                };
+
+                [
+                    format!("Monkey {}:", id),
+                    format!("  Starting items: {}", items.join(", ")),
+                    format!("  Operation: new = {}", operation),
+                    format!("  Test: divisible by {}", primes[id]),
+                    format!("    If true: throw to monkey {}", yes),
+                    format!("    If false: throw to monkey {}\n", no),
+                ]
+                .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
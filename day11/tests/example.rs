@@ -0,0 +1,46 @@
+use aoc_common::Solution;
+use day11::Day11;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day11::parse(EXAMPLE).unwrap();
+    assert_eq!(Day11::part1(&input), "10605");
+}
+
+#[test]
+fn part2() {
+    let input = Day11::parse(EXAMPLE).unwrap();
+    assert_eq!(Day11::part2(&input).unwrap(), "2713310158");
+}
+
+fn monkey(id: usize, items: &str, operation: &str, test: i64, yes: usize, no: usize) -> String {
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+        id, items, operation, test, yes, no
+    )
+}
+
+#[test]
+fn worry_levels_that_square_past_an_i64() {
+    let input = [
+        monkey(0, "3037000000", "old * old", 3037000493, 1, 1),
+        monkey(1, "3037000498", "old * 3037000499", 1, 0, 0),
+    ]
+    .join("\n");
+    let input = Day11::parse(&input).unwrap();
+    Day11::part1(&input);
+    Day11::part2(&input);
+}
+
+#[test]
+fn tests_with_too_large_a_common_multiple() {
+    let input = [
+        monkey(0, "1", "old + 1", 2000003, 1, 1),
+        monkey(1, "1", "old + 1", 2000029, 0, 0),
+    ]
+    .join("\n");
+    let err = Day11::parse(&input).err().unwrap();
+    assert_eq!((err.line, err.column), (11, 22));
+}
@@ -0,0 +1,93 @@
+use aoc_common::grid::{Grid, Point};
+use aoc_common::parse;
+use aoc_common::random::Rng;
+use aoc_common::search;
+use aoc_common::{ParseError, Solution};
+
+const LOWEST: u8 = b'a';
+const START: u8 = b'S';
+const EXIT: u8 = b'E';
+const EXIT_ELEV: u8 = b'z';
+
+type Map = Grid<u8>;
+
+fn elevation(map: &Map, pos: Point) -> u8 {
+    match map[pos] {
+        START => LOWEST,
+        EXIT => EXIT_ELEV,
+        b => b,
+    }
+}
+
+// fewest steps from any of starts to the exit, climbing at most one up at a time
+fn climb<I>(map: &Map, starts: I) -> Option<usize>
+where
+    I: IntoIterator<Item = Point>,
+{
+    let path = search::bfs(
+        starts,
+        |&pos| {
+            let limit = elevation(map, pos) + 1;
+            map.neighbors4(pos).filter(move |&next| elevation(map, next) <= limit)
+        },
+        |&pos| map[pos] == EXIT,
+    )?;
+
+    Some(path.cost)
+}
+
+fn show(steps: Option<usize>) -> String {
+    steps.map_or_else(|| "unreachable".to_string(), |n| n.to_string())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(input, "an elevation", |c| {
+            (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c as u8)
+        })?;
+
+        for (target, name) in [(START, "a start `S`"), (EXIT, "an exit `E`")] {
+            if map.position(|&b| b == target).is_none() {
+                return Err(parse::missing(input, name));
+            }
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> String {
+        let start = map.position(|&b| b == START).unwrap();
+        show(climb(map, [start]))
+    }
+
+    fn part2(map: &Self::Input) -> Option<String> {
+        let lowest = map.points().filter(|&p| elevation(map, p) == LOWEST);
+        Some(show(climb(map, lowest)))
+    }
+
+    // a size wide hill rising from left to right, with a trail along one row
+    // that climbs it one step at a time and dips around it everywhere else
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let (width, height) = (size.max(26), (size / 2).max(3));
+        let trail = rng.index(height);
+
+        let map = Grid::from_fn(width, height, |(i, j)| {
+            let base = (j * 25 / (width - 1)) as u8;
+            let dip = if i == trail || rng.chance(0.5) { 0 } else { rng.range(1, 3) as u8 };
+
+            match (i == trail, j) {
+                (true, 0) => 'S',
+                (true, j) if j == width - 1 => 'E',
+                _ => (LOWEST + base.saturating_sub(dip)) as char,
+            }
+        });
+
+        map.to_string()
+    }
+}
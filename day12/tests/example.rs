@@ -0,0 +1,23 @@
+use aoc_common::Solution;
+use day12::Day12;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day12::parse(EXAMPLE).unwrap();
+    assert_eq!(Day12::part1(&input), "31");
+}
+
+#[test]
+fn part2() {
+    let input = Day12::parse(EXAMPLE).unwrap();
+    assert_eq!(Day12::part2(&input).unwrap(), "29");
+}
+
+#[test]
+fn unreachable_exit() {
+    let input = Day12::parse("Sbcd\nzzzE\n").unwrap();
+    assert_eq!(Day12::part1(&input), "unreachable");
+    assert_eq!(Day12::part2(&input).unwrap(), "unreachable");
+}
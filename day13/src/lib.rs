@@ -0,0 +1,158 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NestedList<T> {
+    List(Vec<NestedList<T>>),
+    Item(T),
+}
+
+// deeper lists would overflow the stack of the recursive parser and comparison
+const MAX_DEPTH: usize = 256;
+
+fn parse_list(s: &mut Scanner, depth: usize) -> parse::Result<NestedList<i64>> {
+    if !s.eat("[") {
+        return Ok(NestedList::Item(s.number()?));
+    }
+    if depth == MAX_DEPTH {
+        return Err(s.error("a list nested less deeply"));
+    }
+
+    let mut res = Vec::new();
+    if !s.eat("]") {
+        loop {
+            res.push(parse_list(s, depth + 1)?);
+            if s.choice(&[",", "]"])? == "]" {
+                break;
+            }
+        }
+    }
+
+    Ok(NestedList::List(res))
+}
+
+// a single packet, such as `[1,[2,[3]]]`
+pub fn parse_packet(line: &str) -> Result<NestedList<i64>, ParseError> {
+    let mut s = Scanner::new(line);
+    let packet = parse_list(&mut s, 0)?;
+    s.finish()?;
+    Ok(packet)
+}
+
+// the order of two packets, Less meaning they are in the right order
+pub fn compare_lists(l1: &NestedList<i64>, l2: &NestedList<i64>) -> Ordering {
+    match (l1, l2) {
+        (NestedList::Item(a), NestedList::Item(b)) => a.cmp(b),
+        (NestedList::Item(a), NestedList::List(b)) => compare_lists(
+            &NestedList::List(vec![NestedList::Item(*a)]),
+            &NestedList::List(b.clone()),
+        ),
+        (NestedList::List(a), NestedList::Item(b)) => compare_lists(
+            &NestedList::List(a.clone()),
+            &NestedList::List(vec![NestedList::Item(*b)]),
+        ),
+        (NestedList::List(a), NestedList::List(b)) => {
+            let (iter1, mut iter2) = (a.iter(), b.iter());
+
+            for item1 in iter1 {
+                if let Some(item2) = iter2.next() {
+                    match compare_lists(item1, item2) {
+                        Ordering::Equal => {}
+                        Ordering::Less => return Ordering::Less,
+                        Ordering::Greater => return Ordering::Greater,
+                    }
+                } else {
+                    return Ordering::Greater;
+                }
+            }
+
+            if iter2.next().is_none() {
+                Ordering::Equal
+            } else {
+                Ordering::Less
+            }
+        }
+    }
+}
+
+// a list nested at most 4 deep
+fn generate_list(rng: &mut Rng, depth: usize) -> String {
+    let items = (0..rng.range(0, 4))
+        .map(|_| {
+            if depth < 3 && rng.chance(0.3) {
+                generate_list(rng, depth + 1)
+            } else {
+                rng.range(0, 10).to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", items.join(","))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = Vec<NestedList<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lists = Vec::new();
+
+        // pairs of packets, separated by empty lines
+        for (i, line) in input.lines().enumerate() {
+            let mut s = Scanner::at_line(line, i + 1);
+            if i % 3 == 2 {
+                s.finish()?;
+            } else {
+                lists.push(parse_list(&mut s, 0)?);
+                s.finish()?;
+            }
+        }
+
+        if lists.len() % 2 == 1 {
+            return Err(parse::missing(input, "the second packet of the pair"));
+        }
+
+        Ok(lists)
+    }
+
+    fn part1(lists: &Self::Input) -> String {
+        lists
+            .chunks(2)
+            .map(|l| compare_lists(&l[0], &l[1]) == Ordering::Less)
+            .enumerate()
+            .filter(|(_, b)| *b)
+            .fold(0, |acc, (i, _)| acc + i + 1)
+            .to_string()
+    }
+
+    fn part2(lists: &Self::Input) -> Option<String> {
+        // a divider's index is one past the packets sorting below it, and the
+        // second divider also sorts after the first
+        let below = |div: &NestedList<i64>| {
+            lists
+                .iter()
+                .filter(|l| compare_lists(l, div) == Ordering::Less)
+                .count()
+        };
+
+        let div1 = NestedList::List(vec![NestedList::List(vec![NestedList::Item(2)])]);
+        let div2 = NestedList::List(vec![NestedList::List(vec![NestedList::Item(6)])]);
+        let idx1 = below(&div1) + 1;
+        let idx2 = below(&div2) + 2;
+
+        Some((idx1 * idx2).to_string())
+    }
+
+    // size pairs of packets
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n{}\n", generate_list(rng, 0), generate_list(rng, 0)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
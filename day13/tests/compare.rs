@@ -0,0 +1,38 @@
+use std::cmp::Ordering;
+
+use day13::{compare_lists, parse_packet};
+
+fn compare(left: &str, right: &str) -> Ordering {
+    compare_lists(&parse_packet(left).unwrap(), &parse_packet(right).unwrap())
+}
+
+#[test]
+fn pairs_of_the_example() {
+    assert_eq!(compare("[1,1,3,1,1]", "[1,1,5,1,1]"), Ordering::Less);
+    assert_eq!(compare("[[1],[2,3,4]]", "[[1],4]"), Ordering::Less);
+    assert_eq!(compare("[9]", "[[8,7,6]]"), Ordering::Greater);
+    assert_eq!(compare("[[4,4],4,4]", "[[4,4],4,4,4]"), Ordering::Less);
+    assert_eq!(compare("[7,7,7,7]", "[7,7,7]"), Ordering::Greater);
+    assert_eq!(compare("[]", "[3]"), Ordering::Less);
+    assert_eq!(compare("[[[]]]", "[[]]"), Ordering::Greater);
+}
+
+#[test]
+fn items_compare_as_lists_of_one() {
+    assert_eq!(compare("[[2]]", "[2]"), Ordering::Equal);
+    assert_eq!(compare("[[[6]]]", "[6]"), Ordering::Equal);
+}
+
+#[test]
+fn packets_are_whole_lines() {
+    assert!(parse_packet("[1,2]]").is_err());
+    assert!(parse_packet("[1,2").is_err());
+}
+
+#[test]
+fn deep_nesting_is_an_error() {
+    let deep = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(parse_packet(&deep(256)).is_ok());
+    assert!(parse_packet(&deep(257)).is_err());
+    assert!(parse_packet(&"[".repeat(300_000)).is_err());
+}
@@ -0,0 +1,24 @@
+use aoc_common::Solution;
+use day13::Day13;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day13::parse(EXAMPLE).unwrap();
+    assert_eq!(Day13::part1(&input), "13");
+}
+
+#[test]
+fn part2() {
+    let input = Day13::parse(EXAMPLE).unwrap();
+    assert_eq!(Day13::part2(&input).unwrap(), "140");
+}
+
+#[test]
+fn packets_equal_to_a_divider() {
+    // [2] equals the first divider and [[6]] the second, and the dividers
+    // sort before packets equal to them
+    let input = Day13::parse("[2]\n[1]\n\n[[6]]\n[7]\n").unwrap();
+    assert_eq!(Day13::part2(&input).unwrap(), (2 * 4).to_string());
+}
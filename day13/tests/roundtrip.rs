@@ -0,0 +1,37 @@
+use aoc_common::Solution;
+use day13::{Day13, NestedList};
+use proptest::prelude::*;
+
+fn packet() -> impl Strategy<Value = NestedList<i64>> {
+    let item = (0..100i64).prop_map(NestedList::Item);
+    item.prop_recursive(4, 32, 5, |inner| {
+        prop::collection::vec(inner, 0..5).prop_map(NestedList::List)
+    })
+    .prop_map(|list| match list {
+        NestedList::Item(_) => NestedList::List(vec![list]),
+        list => list,
+    })
+}
+
+fn show(list: &NestedList<i64>) -> String {
+    match list {
+        NestedList::Item(n) => n.to_string(),
+        NestedList::List(items) => {
+            format!("[{}]", items.iter().map(show).collect::<Vec<_>>().join(","))
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn packets_parse_back(pairs in prop::collection::vec((packet(), packet()), 1..8)) {
+        let input = pairs
+            .iter()
+            .map(|(a, b)| format!("{}\n{}\n", show(a), show(b)))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let expected = pairs.into_iter().flat_map(|(a, b)| [a, b]).collect::<Vec<_>>();
+        prop_assert_eq!(Day13::parse(&input).unwrap(), expected);
+    }
+}
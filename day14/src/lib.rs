@@ -0,0 +1,248 @@
+use aoc_common::grid::{Grid, Point};
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::render::{Frame, Recorder, Rgb};
+use aoc_common::step::Simulation;
+use aoc_common::{ParseError, Solution};
+use std::cmp;
+
+// rock paths are made of (x, y) points, while the map is indexed by (y, x)
+type Line = Vec<(usize, usize)>;
+type Map = Grid<char>;
+
+const EMPTY: char = ' ';
+const ROCK: char = '█';
+const SAND: char = 'O';
+
+// how far right and down rocks can be, which bounds the size of the map and
+// the sand that piles up on its floor
+const MAX_X: usize = 1000;
+const MAX_Y: usize = 200;
+
+fn frame(map: &Map) -> Frame {
+    map.map(|&tile| match tile {
+        ROCK => Rgb(110, 100, 90),
+        SAND => Rgb(230, 200, 120),
+        _ => Rgb(20, 20, 30),
+    })
+}
+
+fn parse_point(s: &mut Scanner) -> parse::Result<(usize, usize)> {
+    let x = s.number_in(0, MAX_X)?;
+    s.expect(",")?;
+    Ok((x, s.number_in(0, MAX_Y)?))
+}
+
+fn parse_lines(s: &mut Scanner) -> parse::Result<Line> {
+    let mut line = vec![parse_point(s)?];
+
+    while s.eat(" -> ") {
+        let (prev, at) = (line[line.len() - 1], s.clone());
+        let curr = parse_point(s)?;
+        if prev.0 != curr.0 && prev.1 != curr.1 {
+            return Err(at.error("a point in line with the previous one"));
+        }
+        line.push(curr);
+    }
+
+    Ok(line)
+}
+
+fn drop_sand(map: &mut Map, drop_point: Point) -> bool {
+    let mut pos = drop_point;
+
+    while pos.0 < map.height() - 1 {
+        // straight down, then down to the left, then down to the right
+        let next = [(1, 0), (1, -1), (1, 1)]
+            .into_iter()
+            .filter_map(|d| map.offset(pos, d))
+            .find(|&p| map[p] == EMPTY);
+
+        match next {
+            Some(next) => pos = next,
+            None => {
+                map[pos] = SAND;
+                return true;
+            }
+        }
+    }
+
+    map[pos] = SAND;
+    false
+}
+
+pub struct Cave {
+    map: Map,
+    sand_point: Point,
+}
+
+// the sand coming in a grain at a time, until it falls into the abyss or,
+// with the floor of part 2, blocks the source
+struct Pour {
+    map: Map,
+    sand_point: Point,
+    floor: bool,
+    grains: usize,
+    over: bool,
+}
+
+impl Simulation for Pour {
+    fn step(&mut self) -> bool {
+        if self.over {
+            return false;
+        }
+
+        let rests = drop_sand(&mut self.map, self.sand_point);
+        if rests || self.floor {
+            self.grains += 1;
+        }
+        self.over = !rests && !self.floor || self.map[self.sand_point] == SAND;
+        true
+    }
+
+    fn counters(&self) -> Vec<(String, i64)> {
+        vec![("grains".to_string(), self.grains as i64)]
+    }
+
+    fn render(&self) -> String {
+        self.map.to_string()
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input, parse_lines)?;
+        if lines.is_empty() {
+            return Err(parse::missing(input, "a rock path"));
+        }
+
+        let max_bounds = lines
+            .iter()
+            .flatten()
+            .copied()
+            .reduce(|(accx, accy), (x, y)| (cmp::max(accx, x), cmp::max(accy, y)))
+            .unwrap();
+
+        let min_bounds = lines
+            .iter()
+            .flatten()
+            .copied()
+            .reduce(|(accx, accy), (x, y)| (cmp::min(accx, x), cmp::min(accy, y)))
+            .unwrap();
+
+        let min_y = 0;
+        let max_y = max_bounds.1 + 2;
+        let min_x = cmp::min(min_bounds.0, 500).saturating_sub(max_y);
+        let max_x = cmp::max(max_bounds.0, 500) + max_y;
+
+        let sand_point = (0, 500 - min_x);
+        lines
+            .iter_mut()
+            .flatten()
+            .for_each(|p| *p = (p.0 - min_x, p.1 - min_y));
+
+        let mut map = Grid::new(max_x - min_x, max_y - min_y, EMPTY);
+
+        lines.iter().for_each(|line| {
+            line.iter().reduce(|prev, curr| {
+                if prev.0 == curr.0 {
+                    for i in cmp::min(prev.1, curr.1)..=cmp::max(prev.1, curr.1) {
+                        map[(i, curr.0)] = ROCK;
+                    }
+                }
+
+                if prev.1 == curr.1 {
+                    for j in cmp::min(prev.0, curr.0)..=cmp::max(prev.0, curr.0) {
+                        map[(curr.1, j)] = ROCK;
+                    }
+                }
+
+                curr
+            });
+        });
+
+        Ok(Cave { map, sand_point })
+    }
+
+    fn part1(cave: &Self::Input) -> String {
+        let mut map = cave.map.clone();
+
+        // rocks wide enough catch all the sand, until it blocks the source
+        let mut iter = 0;
+        while map[cave.sand_point] != SAND && drop_sand(&mut map, cave.sand_point) {
+            iter += 1;
+        }
+
+        iter.to_string()
+    }
+
+    fn part2(cave: &Self::Input) -> Option<String> {
+        let mut map = cave.map.clone();
+
+        let mut iter = 0;
+        while map[cave.sand_point] != SAND {
+            drop_sand(&mut map, cave.sand_point);
+            iter += 1;
+        }
+
+        Some(iter.to_string())
+    }
+
+    fn simulate(cave: &Self::Input, part: u32) -> Option<Box<dyn Simulation + '_>> {
+        Some(Box::new(Pour {
+            map: cave.map.clone(),
+            sand_point: cave.sand_point,
+            floor: part == 2,
+            grains: 0,
+            over: false,
+        }))
+    }
+
+    // the sand of part 2 piling up, a grain per frame
+    fn visualize(cave: &Self::Input, rec: &mut Recorder) -> bool {
+        let mut map = cave.map.clone();
+
+        rec.record(|| frame(&map));
+        while map[cave.sand_point] != SAND {
+            drop_sand(&mut map, cave.sand_point);
+            rec.record(|| frame(&map));
+        }
+
+        true
+    }
+
+    // size rock paths below where the sand comes in. sand can pile up no higher
+    // than half the width of the rocks above the top one, so keeping them deeper
+    // than that has it always fall off the rocks before it blocks its way in
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let spread = (size as i64).clamp(10, 50);
+        let top = spread + 15;
+
+        (0..size.max(1))
+            .map(|_| {
+                let x = rng.range(500 - spread, 500 + spread);
+                let mut at = (x, rng.range(top, top + 2 * spread));
+                let mut points = vec![format!("{},{}", at.0, at.1)];
+
+                // alternating horizontal and vertical lines
+                for k in 0..rng.range(1, 4) {
+                    let step = rng.range(-6, 6);
+                    if k % 2 == 0 {
+                        at.0 = (at.0 + step).max(0);
+                    } else {
+                        at.1 = (at.1 + step).max(top);
+                    }
+                    points.push(format!("{},{}", at.0, at.1));
+                }
+
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,29 @@
+use aoc_common::Solution;
+use day14::Day14;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day14::parse(EXAMPLE).unwrap();
+    assert_eq!(Day14::part1(&input), "24");
+}
+
+#[test]
+fn part2() {
+    let input = Day14::parse(EXAMPLE).unwrap();
+    assert_eq!(Day14::part2(&input).unwrap(), "93");
+}
+
+#[test]
+fn rocks_too_deep() {
+    let err = Day14::parse("498,4 -> 498,201\n").err().unwrap();
+    assert_eq!((err.line, err.column), (1, 14));
+}
+
+#[test]
+fn rocks_that_catch_all_the_sand() {
+    let input = Day14::parse("0,200 -> 1000,200\n").unwrap();
+    assert_eq!(Day14::part1(&input), "40000");
+    assert_eq!(Day14::part2(&input).unwrap(), "40000");
+}
@@ -0,0 +1,193 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::point::Point2;
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+
+// how far from the origin anything can be, so distances fit with room to spare
+const LIMIT: i64 = 1_000_000_000;
+
+// the largest search area, which part 2 goes through a row at a time
+const MAX_SEARCH: i64 = 4_000_000;
+
+pub struct Sensor {
+    pos: Point2,
+    beacon: Point2,
+}
+
+fn parse_point(s: &mut Scanner) -> parse::Result<Point2> {
+    s.expect("x=")?;
+    let x = s.number_in(-LIMIT, LIMIT)?;
+    s.expect(", y=")?;
+    Ok(Point2::new(x, s.number_in(-LIMIT, LIMIT)?))
+}
+
+fn parse_sensor(s: &mut Scanner) -> parse::Result<Sensor> {
+    s.expect("Sensor at ")?;
+    let pos = parse_point(s)?;
+    s.expect(": closest beacon is at ")?;
+    let beacon = parse_point(s)?;
+    s.finish()?;
+
+    Ok(Sensor { pos, beacon })
+}
+
+fn parse_header(
+    s: Option<Scanner>,
+    input: &str,
+    name: &str,
+    lo: i64,
+    hi: i64,
+) -> parse::Result<i64> {
+    let mut s = s.ok_or_else(|| parse::missing(input, name))?;
+    let n = s.number_in(lo, hi)?;
+    s.finish()?;
+    Ok(n)
+}
+
+fn slice_bounds(sen: &Sensor, row: i64) -> Option<(i64, i64)> {
+    let dist = (sen.pos.y - row).abs();
+    let range = sen.pos.manhattan(sen.beacon);
+
+    if dist > range {
+        None
+    } else {
+        Some((sen.pos.x - range + dist, sen.pos.x + range - dist))
+    }
+}
+
+fn add_segment(segments: &mut Vec<(i64, i64)>, slice: (i64, i64)) {
+    let mut i = 0;
+    while i < segments.len() && slice.0 > segments[i].1 {
+        i += 1;
+    }
+
+    if i == segments.len() {
+        segments.push(slice);
+    } else if slice.1 < segments[i].0 {
+        segments.insert(i, slice);
+    } else if slice.0 >= segments[i].0 {
+        if slice.1 > segments[i].1 {
+            add_segment(segments, (segments[i].1 + 1, slice.1));
+        }
+    } else if slice.1 > segments[i].0 {
+        segments[i].0 = slice.0;
+        if slice.1 > segments[i].1 {
+            add_segment(segments, (segments[i].1 + 1, slice.1));
+        }
+    } else {
+        segments.insert(i, (slice.0, slice.1 - 1));
+    }
+}
+
+pub struct Report {
+    row: i64,
+    max: i64,
+    sensors: Vec<Sensor>,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Report;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Scanner::at_line(line, i + 1));
+
+        let row = parse_header(lines.next(), input, "the row to inspect", -LIMIT, LIMIT)?;
+        let max = parse_header(lines.next(), input, "the search limit", 0, MAX_SEARCH)?;
+        let sensors = lines
+            .map(|mut s| parse_sensor(&mut s))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Report { row, max, sensors })
+    }
+
+    fn part1(report: &Self::Input) -> String {
+        let Report { row, sensors, .. } = report;
+
+        let mut beacons = sensors
+            .iter()
+            .filter(|s| s.beacon.y == *row)
+            .map(|s| s.beacon.x)
+            .collect::<Vec<_>>();
+        beacons.sort();
+        beacons.dedup();
+
+        let total = sensors
+            .iter()
+            .filter_map(|s| slice_bounds(s, *row))
+            .fold(Vec::new(), |mut segments, slice| {
+                add_segment(&mut segments, slice);
+                segments
+            })
+            .iter()
+            .map(|(start, end)| *end - *start + 1)
+            .sum::<i64>();
+
+        (total - beacons.len() as i64).to_string()
+    }
+
+    fn part2(report: &Self::Input) -> Option<String> {
+        let Report { max, sensors, .. } = report;
+        let max = *max;
+
+        for row in 0..=max {
+            let mut segments = Vec::new();
+            sensors
+                .iter()
+                .filter_map(|s| slice_bounds(s, row))
+                .for_each(|slice| add_segment(&mut segments, slice));
+
+            for window in segments.windows(2) {
+                let (prev, curr) = (window[0], window[1]);
+                if curr.0 == prev.1 + 2 && prev.1 < max {
+                    return Some(((prev.1 + 1) * 4000000 + row).to_string());
+                }
+
+                if curr.1 > max {
+                    break;
+                }
+            }
+        }
+
+        Some(0.to_string())
+    }
+
+    // a search area of size thousand squared, with 20 sensors and one more
+    // for every 10 of size. four huge sensors around the distress beacon cover
+    // all the rest of the area, the others are noise inside what they cover
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let max = size.clamp(1, 4000) as i64 * 1000;
+        let beacon = Point2::new(rng.range(1, max - 1), rng.range(1, max - 1));
+        let reach = max + 1;
+
+        let mut sensors = Vec::new();
+        for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let pos = beacon + Point2::new(sx, sy) * reach;
+            sensors.push((pos, beacon + Point2::new(-sx * (reach - 1), sy * reach)));
+        }
+
+        while sensors.len() < 24 + size / 10 {
+            let pos = Point2::new(rng.range(0, max), rng.range(0, max));
+            let range = (pos.manhattan(beacon) - 1).min(max / 4);
+            let dx = rng.range(-range, range);
+            let dy = (range - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+            sensors.push((pos, pos + Point2::new(dx, dy)));
+        }
+        rng.shuffle(&mut sensors);
+
+        let mut res = format!("{}\n{}\n", max / 2, max);
+        for (pos, beacon) in sensors {
+            res.push_str(&format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                pos.x, pos.y, beacon.x, beacon.y
+            ));
+        }
+        res
+    }
+}
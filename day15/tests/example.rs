@@ -0,0 +1,29 @@
+use aoc_common::Solution;
+use day15::Day15;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day15::parse(EXAMPLE).unwrap();
+    assert_eq!(Day15::part1(&input), "26");
+}
+
+#[test]
+fn part2() {
+    let input = Day15::parse(EXAMPLE).unwrap();
+    assert_eq!(Day15::part2(&input).unwrap(), "56000011");
+}
+
+#[test]
+fn search_areas_too_large() {
+    let err = Day15::parse("10\n4000001\n").err().unwrap();
+    assert_eq!((err.line, err.column), (2, 1));
+}
+
+#[test]
+fn sensors_too_far_away() {
+    let input = "10\n20\nSensor at x=2, y=18: closest beacon is at x=-1000000001, y=15\n";
+    let err = Day15::parse(input).err().unwrap();
+    assert_eq!((err.line, err.column), (3, 45));
+}
@@ -0,0 +1,267 @@
+use aoc_common::bitset::GrowBitSet;
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+use std::cmp;
+use std::collections::{HashMap, VecDeque};
+
+type Graph = Vec<(u32, Vec<usize>)>;
+
+// far from overflowing once added up over every valve and minute
+const MAX_FLOW: u32 = 1000;
+
+type Valve<'a> = (&'a str, u32, Vec<(&'a str, Scanner<'a>)>);
+
+fn parse_name<'a>(s: &mut Scanner<'a>) -> parse::Result<&'a str> {
+    let at = s.clone();
+    let name = s.take_while(|c| c.is_ascii_uppercase());
+    if name.len() != 2 {
+        return Err(at.error("a two-letter valve name"));
+    }
+    Ok(name)
+}
+
+fn parse_valve<'a>(s: &mut Scanner<'a>) -> parse::Result<Valve<'a>> {
+    s.expect("Valve ")?;
+    let name = parse_name(s)?;
+    s.expect(" has flow rate=")?;
+    let pressure = s.number_in(0, MAX_FLOW)?;
+    s.choice(&["; tunnels lead to valves ", "; tunnel leads to valve "])?;
+
+    // keep where every neighbour was named, in case it is never defined
+    let mut neighs = Vec::new();
+    loop {
+        let at = s.clone();
+        neighs.push((parse_name(s)?, at));
+        if !s.eat(", ") {
+            break;
+        }
+    }
+
+    Ok((name, pressure, neighs))
+}
+
+// steps from valve to every other one, None for those out of reach
+fn distances(graph: &Graph, from: usize) -> Vec<Option<u32>> {
+    let mut dist = vec![None; graph.len()];
+    dist[from] = Some(0);
+
+    let mut queue = VecDeque::from([from]);
+    while let Some(valve) = queue.pop_front() {
+        let d = dist[valve].unwrap();
+        for &next in &graph[valve].1 {
+            if dist[next].is_none() {
+                dist[next] = Some(d + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    dist
+}
+
+// only the valves with a flow are worth walking to, the others are just the
+// way between them
+struct Network {
+    flows: Vec<u32>,
+    // from every working valve, and last from the one we start at
+    dist: Vec<Vec<Option<u32>>>,
+}
+
+impl Network {
+    fn new(tunnels: &Tunnels) -> Self {
+        let graph = &tunnels.graph;
+        let working = (0..graph.len())
+            .filter(|&valve| graph[valve].0 > 0)
+            .collect::<Vec<_>>();
+
+        let dist = working
+            .iter()
+            .chain([&tunnels.start])
+            .map(|&from| {
+                let dist = distances(graph, from);
+                working.iter().map(|&to| dist[to]).collect()
+            })
+            .collect();
+
+        let flows = working.iter().map(|&valve| graph[valve].0).collect();
+        Self { flows, dist }
+    }
+
+    fn start(&self) -> usize {
+        self.flows.len()
+    }
+
+    // calls visit with the valves opened so far and the pressure they release,
+    // for every order of opening them that fits in the time left
+    fn walk<F>(&self, at: usize, time: u32, opened: &mut GrowBitSet, pressure: u32, visit: &mut F)
+    where
+        F: FnMut(&GrowBitSet, u32),
+    {
+        visit(opened, pressure);
+
+        for (next, &flow) in self.flows.iter().enumerate() {
+            let Some(d) = self.dist[at][next] else {
+                continue;
+            };
+            if opened.contains(next) || d + 1 >= time {
+                continue;
+            }
+
+            let left = time - d - 1;
+            opened.insert(next);
+            self.walk(next, left, opened, pressure + flow * left, visit);
+            opened.remove(next);
+        }
+    }
+
+    // the most pressure each set of valves releases when opened in time
+    fn best(&self, time: u32) -> HashMap<GrowBitSet, u32> {
+        let mut best = HashMap::new();
+        self.walk(
+            self.start(),
+            time,
+            &mut GrowBitSet::new(),
+            0,
+            &mut |opened, pressure| {
+                let entry = best.entry(opened.clone()).or_insert(0);
+                *entry = cmp::max(*entry, pressure);
+            },
+        );
+        best
+    }
+}
+
+pub struct Tunnels {
+    graph: Graph,
+    start: usize,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Input = Tunnels;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let valves = parse::lines(input, parse_valve)?;
+        let n = valves.len();
+
+        let valve_map = valves
+            .iter()
+            .enumerate()
+            .map(|(idx, valve)| (valve.0, idx))
+            .collect::<HashMap<_, _>>();
+
+        let mut graph = vec![(0, Vec::new()); n];
+        for (idx, valve) in valves.iter().enumerate() {
+            graph[idx].0 = valve.1;
+
+            for (neigh, at) in &valve.2 {
+                let neight_idx = *valve_map
+                    .get(neigh)
+                    .ok_or_else(|| at.error("a valve defined in the input"))?;
+                graph[idx].1.push(neight_idx);
+            }
+        }
+
+        let start = *valve_map
+            .get("AA")
+            .ok_or_else(|| parse::missing(input, "valve AA"))?;
+        Ok(Tunnels { graph, start })
+    }
+
+    fn part1(tunnels: &Self::Input) -> String {
+        let best = Network::new(tunnels).best(30);
+        best.values().max().unwrap().to_string()
+    }
+
+    // the two of us open valves the other one does not
+    fn part2(tunnels: &Self::Input) -> Option<String> {
+        let mut best = Network::new(tunnels)
+            .best(26)
+            .into_iter()
+            .collect::<Vec<_>>();
+        best.sort_by_key(|&(_, pressure)| cmp::Reverse(pressure));
+
+        // in order of pressure, so both loops stop once nothing can do better
+        let mut res = 0;
+        for (i, (mine, p)) in best.iter().enumerate() {
+            if 2 * p <= res {
+                break;
+            }
+            for (theirs, q) in &best[i..] {
+                if p + q <= res {
+                    break;
+                }
+                if mine.is_disjoint(theirs) {
+                    res = p + q;
+                    break;
+                }
+            }
+        }
+
+        Some(res.to_string())
+    }
+
+    // size valves (at most 676, as many as there are two letter names), a
+    // quarter of them with a flow of up to 15 of them, joined into one network
+    // of tunnels
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let n = size.clamp(2, 26 * 26);
+
+        let mut names = vec!["AA".to_string()];
+        while names.len() < n {
+            let name = format!("{}{}", rng.char_in('A', 'Z'), rng.char_in('A', 'Z'));
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        let mut flows = vec![0; n];
+        let mut working = (1..n).collect::<Vec<_>>();
+        rng.shuffle(&mut working);
+        for &valve in working.iter().take((n / 4).clamp(1, 15)) {
+            flows[valve] = rng.range(1, 25);
+        }
+
+        // a tree keeps every valve reachable, then a few shortcuts
+        let mut tunnels = vec![Vec::new(); n];
+        let mut join = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for valve in 1..n {
+            join(valve, rng.index(valve));
+        }
+        for _ in 0..n / 4 {
+            join(rng.index(n), rng.index(n));
+        }
+
+        let mut lines = (0..n)
+            .map(|valve| {
+                let to = tunnels[valve]
+                    .iter()
+                    .map(|&t| names[t].as_str())
+                    .collect::<Vec<_>>();
+                let lead = match to.len() {
+                    1 => "tunnel leads to valve",
+                    _ => "tunnels lead to valves",
+                };
+                let (name, flow) = (&names[valve], flows[valve]);
+                format!(
+                    "Valve {} has flow rate={}; {} {}\n",
+                    name,
+                    flow,
+                    lead,
+                    to.join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut lines);
+        lines.concat()
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_common::Solution;
+use day16::Day16;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day16::parse(EXAMPLE).unwrap();
+    assert_eq!(Day16::part1(&input), "1651");
+}
+
+#[test]
+fn part2() {
+    let input = Day16::parse(EXAMPLE).unwrap();
+    assert_eq!(Day16::part2(&input).unwrap(), "1707");
+}
+
+// AA in the middle of 69 other valves, the two working ones listed last
+fn star() -> String {
+    let names = (b'B'..=b'Z')
+        .flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", a as char, b as char)))
+        .take(69)
+        .collect::<Vec<_>>();
+
+    let mut lines = vec![format!(
+        "Valve AA has flow rate=0; tunnels lead to valves {}",
+        names.join(", ")
+    )];
+    for (i, name) in names.iter().enumerate() {
+        let flow = [0, 5, 10][i.saturating_sub(66)];
+        lines.push(format!(
+            "Valve {} has flow rate={}; tunnel leads to valve AA",
+            name, flow
+        ));
+    }
+    lines.join("\n") + "\n"
+}
+
+#[test]
+fn more_valves_than_bits_in_a_word() {
+    let input = Day16::parse(&star()).unwrap();
+    assert_eq!(Day16::part1(&input), (10 * 28 + 5 * 25).to_string());
+    assert_eq!(
+        Day16::part2(&input).unwrap(),
+        (10 * 24 + 5 * 24).to_string()
+    );
+}
+
+#[test]
+fn generated_networks_of_every_size_are_solved() {
+    for size in [2, 60, 676] {
+        let input = Day16::generate(&mut aoc_common::random::Rng::new(1), size);
+        let input = Day16::parse(&input).unwrap();
+        assert!(Day16::part1(&input).parse::<u32>().is_ok());
+        assert!(Day16::part2(&input).unwrap().parse::<u32>().is_ok());
+    }
+}
@@ -0,0 +1,308 @@
+use aoc_common::bitset::BitSet;
+use aoc_common::cycle;
+use aoc_common::grid::Grid;
+use aoc_common::parse;
+use aoc_common::random::Rng;
+use aoc_common::render::{Frame, Recorder, Rgb};
+use aoc_common::step::Simulation;
+use aoc_common::{ParseError, Solution};
+use std::iter;
+
+// a row of the chamber, the 7 columns from the left and the wall on the right
+type Row = BitSet;
+
+const EMPTY_ROW: Row = Row::from_bits(1 << 7);
+const PADDING: usize = 7;
+// how many of the top rows decide where the next rocks can fall
+const SURFACE: usize = 64;
+// how many of the top rows a frame shows
+const WINDOW: usize = 48;
+
+type Rock = Vec<Row>;
+
+#[derive(Clone)]
+pub enum Move {
+    Left,
+    Right,
+}
+
+// a rock drawn with `#`, as one row from the bottom up
+fn parse_rock(shape: &str) -> Rock {
+    let rock = Grid::parse(shape, "a rock piece", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .unwrap();
+
+    rock.rows()
+        .rev()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &piece)| piece)
+                .map(|(i, _)| i)
+                .collect()
+        })
+        .collect()
+}
+
+struct RotVec<T> {
+    vec: Vec<T>,
+    idx: usize,
+}
+
+impl<T> RotVec<T> {
+    fn new(vec: Vec<T>) -> Self {
+        Self { vec, idx: 0 }
+    }
+
+    fn get_next(&mut self) -> &T {
+        self.idx += 1;
+        &self.vec[(self.idx - 1) % self.vec.len()]
+    }
+
+    fn get_idx(&self) -> usize {
+        self.idx % self.vec.len()
+    }
+}
+
+struct Chamber {
+    room: Vec<Row>,
+    rocks: RotVec<Rock>,
+    moves: RotVec<Move>,
+}
+
+impl Chamber {
+    fn new(rocks: &[Rock], moves: &[Move]) -> Self {
+        Self {
+            room: vec![EMPTY_ROW; PADDING],
+            rocks: RotVec::new(rocks.to_vec()),
+            moves: RotVec::new(moves.to_vec()),
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.room.len() - PADDING
+    }
+
+    // what the next rocks depend on: which rock and jet come next, and the top of the tower.
+    // only the top SURFACE rows are kept, which is a guess rather than the exact state:
+    // a rock could in principle slip down a gap deeper than that
+    fn key(&self) -> (usize, usize, Vec<Row>) {
+        let top = self
+            .room
+            .iter()
+            .rev()
+            .take(SURFACE + PADDING)
+            .copied()
+            .collect();
+        (self.rocks.get_idx(), self.moves.get_idx(), top)
+    }
+
+    // the top of the tower between the walls, and the floor once it is in view
+    fn frame(&self) -> Frame {
+        let top = self.room.len() as isize;
+        Grid::from_fn(9, WINDOW, |(i, j)| {
+            // rows of the room count from the bottom up
+            let row = top - 1 - i as isize;
+            if row < -1 {
+                Rgb(20, 20, 30)
+            } else if row == -1 || j == 0 || j == 8 {
+                Rgb(110, 100, 90)
+            } else if self.room[row as usize].contains(j - 1) {
+                Rgb(200, 120, 60)
+            } else {
+                Rgb(20, 20, 30)
+            }
+        })
+    }
+
+    fn drop(&mut self) {
+        let room = &mut self.room;
+        let rock = self.rocks.get_next();
+        let mut pos = (2, room.len() - 4);
+
+        loop {
+            match self.moves.get_next() {
+                Move::Left => {
+                    if pos.0 > 0
+                        && rock
+                            .iter()
+                            .enumerate()
+                            .all(|(i, &piece)| (piece << (pos.0 - 1)).is_disjoint(&room[pos.1 + i]))
+                    {
+                        pos.0 -= 1;
+                    }
+                }
+                Move::Right => {
+                    if rock
+                        .iter()
+                        .enumerate()
+                        .all(|(i, &piece)| (piece << (pos.0 + 1)).is_disjoint(&room[pos.1 + i]))
+                    {
+                        pos.0 += 1;
+                    }
+                }
+            }
+
+            if pos.1 > 0
+                && rock
+                    .iter()
+                    .enumerate()
+                    .all(|(i, &piece)| (piece << pos.0).is_disjoint(&room[pos.1 + i - 1]))
+            {
+                pos.1 -= 1;
+                continue;
+            }
+
+            break;
+        }
+
+        for (i, &piece) in rock.iter().enumerate() {
+            room[pos.1 + i] |= piece << pos.0
+        }
+
+        for (i, &row) in room.iter().rev().take(PADDING).enumerate() {
+            if row != EMPTY_ROW {
+                room.extend(iter::repeat_n(EMPTY_ROW, PADDING - i));
+                break;
+            }
+        }
+    }
+}
+
+// the rocks falling one at a time, up to the number the part asks for
+struct Tower {
+    chamber: Chamber,
+    dropped: usize,
+    n: usize,
+}
+
+impl Simulation for Tower {
+    fn step(&mut self) -> bool {
+        if self.dropped == self.n {
+            return false;
+        }
+        self.chamber.drop();
+        self.dropped += 1;
+        true
+    }
+
+    fn counters(&self) -> Vec<(String, i64)> {
+        vec![
+            ("rocks".to_string(), self.dropped as i64),
+            ("height".to_string(), self.chamber.height() as i64),
+            ("jet".to_string(), self.chamber.moves.get_idx() as i64),
+        ]
+    }
+
+    // the top of the tower, as drawn in the puzzle
+    fn render(&self) -> String {
+        let room = &self.chamber.room[..self.chamber.height()];
+
+        let mut res = String::new();
+        for row in room.iter().rev().take(WINDOW) {
+            let cells = (0..7).map(|j| if row.contains(j) { '#' } else { '.' });
+            res.push('|');
+            res.extend(cells);
+            res.push_str("|\n");
+        }
+        if room.len() <= WINDOW {
+            res.push_str("+-------+\n");
+        }
+        res
+    }
+}
+
+fn solve(rocks: &[Rock], moves: &[Move], n: usize) -> usize {
+    cycle::extrapolate(
+        Chamber::new(rocks, moves),
+        n,
+        Chamber::drop,
+        Chamber::key,
+        |chamber| chamber.height() as i64,
+    ) as usize
+}
+
+fn rocks() -> Vec<Rock> {
+    [
+        "####",
+        ".#.\n###\n.#.",
+        "..#\n..#\n###",
+        "#\n#\n#\n#",
+        "##\n##",
+    ]
+    .into_iter()
+    .map(parse_rock)
+    .collect()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input, |s| {
+            let mut moves = Vec::new();
+            while let Some(c) = s.peek() {
+                match c {
+                    '<' => moves.push(Move::Left),
+                    '>' => moves.push(Move::Right),
+                    _ => break,
+                }
+                s.bump();
+            }
+
+            if moves.is_empty() {
+                return Err(s.error("a jet pattern of `<` and `>`"));
+            }
+            Ok(moves)
+        })?;
+
+        match lines.len() {
+            1 => Ok(lines.remove(0)),
+            _ => Err(parse::missing(input, "a single line")),
+        }
+    }
+
+    fn part1(moves: &Self::Input) -> String {
+        solve(&rocks(), moves, 2022).to_string()
+    }
+
+    fn part2(moves: &Self::Input) -> Option<String> {
+        Some(solve(&rocks(), moves, 1000000000000).to_string())
+    }
+
+    fn simulate(moves: &Self::Input, part: u32) -> Option<Box<dyn Simulation + '_>> {
+        Some(Box::new(Tower {
+            chamber: Chamber::new(&rocks(), moves),
+            dropped: 0,
+            n: if part == 1 { 2022 } else { 1000000000000 },
+        }))
+    }
+
+    // the 2022 rocks of part 1, a frame for each one that comes to rest
+    fn visualize(moves: &Self::Input, rec: &mut Recorder) -> bool {
+        let mut chamber = Chamber::new(&rocks(), moves);
+
+        rec.record(|| chamber.frame());
+        for _ in 0..2022 {
+            chamber.drop();
+            rec.record(|| chamber.frame());
+        }
+
+        true
+    }
+
+    // a jet pattern of size pushes
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| *rng.pick(&['<', '>']))
+            .chain(iter::once('\n'))
+            .collect()
+    }
+}
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day17::Day17;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day17::parse(EXAMPLE).unwrap();
+    assert_eq!(Day17::part1(&input), "3068");
+}
+
+#[test]
+fn part2() {
+    let input = Day17::parse(EXAMPLE).unwrap();
+    assert_eq!(Day17::part2(&input).unwrap(), "1514285714288");
+}
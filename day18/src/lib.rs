@@ -0,0 +1,101 @@
+use aoc_common::parse;
+use aoc_common::point::Point3;
+use aoc_common::random::Rng;
+use aoc_common::search;
+use aoc_common::{ParseError, Solution};
+use std::collections::HashSet;
+
+type Cube = Point3;
+
+// the largest coordinate, which bounds the box the steam fills
+const MAX_COORD: i64 = 40;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Input = Vec<Cube>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut seen = HashSet::new();
+        parse::lines(input, |s| {
+            let start = s.clone();
+            let x = s.number_in(0, MAX_COORD)?;
+            s.expect(",")?;
+            let y = s.number_in(0, MAX_COORD)?;
+            s.expect(",")?;
+            let cube = Point3::new(x, y, s.number_in(0, MAX_COORD)?);
+
+            // a cube listed twice would have its sides counted twice
+            if !seen.insert(cube) {
+                return Err(start.error("a cube not listed before"));
+            }
+            Ok(cube)
+        })
+    }
+
+    fn part1(cubes: &Self::Input) -> String {
+        let n = cubes.len();
+
+        let mut res1 = n * 6;
+        for i in 0..n {
+            for j in i + 1..n {
+                if cubes[i].manhattan(cubes[j]) == 1 {
+                    res1 -= 2;
+                }
+            }
+        }
+
+        res1.to_string()
+    }
+
+    fn part2(cubes: &Self::Input) -> Option<String> {
+        let lava = cubes.iter().copied().collect::<HashSet<_>>();
+
+        // a box with a layer of air all around the droplet, so the steam can
+        // reach every side of it from the corner
+        let (lo, hi) = cubes.iter().fold(
+            (Point3::ZERO, Point3::ZERO),
+            |(lo, hi), c| {
+                (
+                    Point3::new(lo.x.min(c.x), lo.y.min(c.y), lo.z.min(c.z)),
+                    Point3::new(hi.x.max(c.x), hi.y.max(c.y), hi.z.max(c.z)),
+                )
+            },
+        );
+        let (lo, hi) = (lo - Point3::new(1, 1, 1), hi + Point3::new(1, 1, 1));
+        let inside = |p: Point3| {
+            (lo.x..=hi.x).contains(&p.x) && (lo.y..=hi.y).contains(&p.y) && (lo.z..=hi.z).contains(&p.z)
+        };
+
+        let steam = search::reachable([lo], |&p| {
+            p.neighbors6().filter(|&next| inside(next) && !lava.contains(&next))
+        });
+
+        Some(
+            steam
+                .iter()
+                .flat_map(|p| p.neighbors6())
+                .filter(|p| lava.contains(p))
+                .count()
+                .to_string(),
+        )
+    }
+
+    // size different cubes, filling about a third of a box
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = (((size * 3) as f64).cbrt().ceil() as i64 + 1).min(MAX_COORD + 1);
+
+        let mut cubes = HashSet::new();
+        let mut res = String::new();
+        while cubes.len() < size.min((side * side * side) as usize) {
+            let mut coord = || rng.range(0, side - 1);
+            let cube = Point3::new(coord(), coord(), coord());
+            if cubes.insert(cube) {
+                res.push_str(&format!("{},{},{}\n", cube.x, cube.y, cube.z));
+            }
+        }
+        res
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_common::Solution;
+use day18::Day18;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day18::parse(EXAMPLE).unwrap();
+    assert_eq!(Day18::part1(&input), "64");
+}
+
+#[test]
+fn part2() {
+    let input = Day18::parse(EXAMPLE).unwrap();
+    assert_eq!(Day18::part2(&input).unwrap(), "58");
+}
+
+#[test]
+fn cubes_listed_twice() {
+    let err = Day18::parse("1,1,1\n2,1,1\n1,1,1\n").err().unwrap();
+    assert_eq!((err.line, err.column), (3, 1));
+}
+
+#[test]
+fn cubes_too_far_out() {
+    let err = Day18::parse("1,1,1\n1,41,1\n").err().unwrap();
+    assert_eq!((err.line, err.column), (2, 3));
+}
@@ -0,0 +1,233 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+
+// the most a robot can cost in any one resource, which keeps the values the
+// search ranks its states by well within an i64
+const MAX_COST: i32 = 100;
+
+fn parse_cost(s: &mut Scanner, what: &str) -> parse::Result<i32> {
+    s.expect(" costs ")?;
+    let cost = s.number_in(1, MAX_COST)?;
+    s.expect(what)?;
+    Ok(cost)
+}
+
+fn parse_blueprint(s: &mut Scanner) -> parse::Result<Blueprint> {
+    s.expect("Blueprint ")?;
+    s.number::<u32>()?;
+    s.expect(": Each ore robot")?;
+    let ore = parse_cost(s, " ore.")?;
+    s.expect(" Each clay robot")?;
+    let clay = parse_cost(s, " ore.")?;
+    s.expect(" Each obsidian robot")?;
+    let obs1 = parse_cost(s, " ore and ")?;
+    let obs2 = s.number_in(1, MAX_COST)?;
+    s.expect(" clay.")?;
+    s.expect(" Each geode robot")?;
+    let geo1 = parse_cost(s, " ore and ")?;
+    let geo2 = s.number_in(1, MAX_COST)?;
+    s.expect(" obsidian.")?;
+
+    Ok(Blueprint::new(ore, clay, (obs1, obs2), (geo1, geo2)))
+}
+
+pub struct Blueprint {
+    ore: i32,
+    clay: i32,
+    obsidian: (i32, i32),
+    geode: (i32, i32),
+
+    ore_val: i64,
+    clay_val: i64,
+    obsidian_val: i64,
+    geode_val: i64,
+}
+
+impl Blueprint {
+    fn new(ore: i32, clay: i32, obsidian: (i32, i32), geode: (i32, i32)) -> Self {
+        let (mut ore_val, mut clay_val) = (1, 1);
+
+        if clay > ore {
+            clay_val = i64::from(clay / ore).max(1);
+        } else {
+            ore_val = i64::from(ore / clay).max(1);
+        }
+
+        let obsidian_val = i64::from(obsidian.0) * ore_val + i64::from(obsidian.1) * clay_val;
+        let geode_val = i64::from(geode.0) * ore_val + i64::from(geode.1) * obsidian_val;
+
+        Self {
+            ore,
+            clay,
+            obsidian,
+            geode,
+            ore_val,
+            clay_val,
+            obsidian_val,
+            geode_val,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct State {
+    ore: i32,
+    clay: i32,
+    obsidian: i32,
+    geode: i32,
+
+    ore_robot: i32,
+    clay_robot: i32,
+    obsidian_robot: i32,
+    geode_robot: i32,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+
+            ore_robot: 1,
+            clay_robot: 0,
+            obsidian_robot: 0,
+            geode_robot: 0,
+        }
+    }
+
+    fn step(&mut self) {
+        self.ore += self.ore_robot;
+        self.clay += self.clay_robot;
+        self.obsidian += self.obsidian_robot;
+        self.geode += self.geode_robot;
+    }
+}
+
+fn solve(time: i32, max_branches: usize, bp: &Blueprint) -> usize {
+    let mut states = vec![State::new()];
+    for i in 0..time {
+        let mut next_states = Vec::new();
+
+        for mut state in states.into_iter() {
+            state.step();
+
+            next_states.push(state.clone());
+
+            if state.ore >= bp.ore {
+                state.ore -= bp.ore;
+                state.ore -= 1;
+                state.ore_robot += 1;
+                next_states.push(state.clone());
+                state.ore_robot -= 1;
+                state.ore += 1;
+                state.ore += bp.ore;
+            }
+
+            if state.ore >= bp.clay {
+                state.ore -= bp.clay;
+                state.clay -= 1;
+                state.clay_robot += 1;
+                next_states.push(state.clone());
+                state.clay_robot -= 1;
+                state.clay += 1;
+                state.ore += bp.clay;
+            }
+
+            if state.ore >= bp.obsidian.0 && state.clay >= bp.obsidian.1 {
+                state.ore -= bp.obsidian.0;
+                state.clay -= bp.obsidian.1;
+                state.obsidian -= 1;
+                state.obsidian_robot += 1;
+                next_states.push(state.clone());
+                state.obsidian_robot -= 1;
+                state.obsidian += 1;
+                state.clay += bp.obsidian.1;
+                state.ore += bp.obsidian.0;
+            }
+
+            if state.ore >= bp.geode.0 && state.obsidian >= bp.geode.1 {
+                state.ore -= bp.geode.0;
+                state.obsidian -= bp.geode.1;
+                state.geode -= 1;
+                state.geode_robot += 1;
+                next_states.push(state);
+            }
+        }
+
+        if next_states.len() > max_branches {
+            next_states.sort_by_key(|s| -euristic(s, bp, i, time));
+            next_states.drain(max_branches..);
+        }
+
+        states = next_states;
+    }
+
+    states.into_iter().map(|s| s.geode).max().unwrap() as usize
+}
+
+fn euristic(s: &State, bp: &Blueprint, t: i32, total: i32) -> i64 {
+    let robots = i64::from(s.ore_robot) * bp.ore_val
+        + i64::from(s.clay_robot) * bp.clay_val
+        + i64::from(s.obsidian_robot) * bp.obsidian_val * 4
+        + i64::from(s.geode_robot) * bp.geode_val * 16;
+
+    let rocks = i64::from(s.obsidian) * bp.obsidian_val * 4
+        + i64::from(s.geode + 1) * bp.geode_val * 16;
+
+    robots * i64::from(total - t) + rocks
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, parse_blueprint)
+    }
+
+    fn part1(blueprints: &Self::Input) -> String {
+        blueprints
+            .iter()
+            .enumerate()
+            .map(|(i, bp)| (i + 1) * solve(24, 2000, bp))
+            .sum::<usize>()
+            .to_string()
+    }
+
+    fn part2(blueprints: &Self::Input) -> Option<String> {
+        Some(
+            blueprints
+                .iter()
+                .take(3)
+                .map(|bp| solve(32, 2000, bp))
+                .product::<usize>()
+                .to_string(),
+        )
+    }
+
+    // size blueprints, with costs in the same ranges as the puzzle ones
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (1..=size.max(1))
+            .map(|id| {
+                format!(
+                    "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                     Each obsidian robot costs {} ore and {} clay. \
+                     Each geode robot costs {} ore and {} obsidian.\n",
+                    id,
+                    rng.range(2, 4),
+                    rng.range(2, 4),
+                    rng.range(2, 4),
+                    rng.range(5, 20),
+                    rng.range(2, 4),
+                    rng.range(5, 20),
+                )
+            })
+            .collect()
+    }
+}
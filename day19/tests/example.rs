@@ -0,0 +1,37 @@
+use aoc_common::Solution;
+use day19::Day19;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day19::parse(EXAMPLE).unwrap();
+    assert_eq!(Day19::part1(&input), "33");
+}
+
+#[test]
+fn part2() {
+    let input = Day19::parse(EXAMPLE).unwrap();
+    assert_eq!(Day19::part2(&input).unwrap(), "3472");
+}
+
+fn blueprint(costs: [i32; 6]) -> String {
+    format!(
+        "Blueprint 1: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+         Each obsidian robot costs {} ore and {} clay. \
+         Each geode robot costs {} ore and {} obsidian.\n",
+        costs[0], costs[1], costs[2], costs[3], costs[4], costs[5]
+    )
+}
+
+#[test]
+fn costs_as_large_as_can_be() {
+    let input = Day19::parse(&blueprint([1, 100, 100, 100, 1, 100])).unwrap();
+    assert_eq!(Day19::part1(&input), "0");
+}
+
+#[test]
+fn costs_too_large() {
+    let err = Day19::parse(&blueprint([1, 101, 1, 1, 1, 1])).err().unwrap();
+    assert_eq!((err.line, err.column), (1, 64));
+}
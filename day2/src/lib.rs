@@ -0,0 +1,414 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+
+// the rules of the puzzle, see the file for how they are written
+pub const ROCK_PAPER_SCISSORS: &str = include_str!("../rules/rps");
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = include_str!("../rules/rpsls");
+
+// a move of a game, as its place in the cycle
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Move(pub usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+// the most points a shape or an outcome can be worth, so a round always fits a u32
+const MAX_POINTS: u32 = 1_000_000;
+
+const OUTCOMES: [(&str, Outcome); 3] = [
+    ("lose", Outcome::Lose),
+    ("draw", Outcome::Draw),
+    ("win", Outcome::Win),
+];
+
+#[derive(Debug)]
+struct Shape {
+    name: String,
+    theirs: char,
+    ours: char,
+    score: u32,
+}
+
+// a cyclic game of an odd number of moves, each beating half of the others
+#[derive(Debug)]
+pub struct Game {
+    shapes: Vec<Shape>,
+    // letter and points of losing, drawing and winning
+    outcomes: [(char, u32); 3],
+}
+
+// a line of the strategy guide, with the second letter left to be interpreted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round {
+    pub theirs: Move,
+    pub code: char,
+}
+
+#[derive(Debug)]
+pub struct Guide {
+    pub game: Game,
+    pub rounds: Vec<Round>,
+}
+
+// a single capital letter
+fn letter(s: &mut Scanner) -> parse::Result<char> {
+    match s.peek() {
+        Some(c) if c.is_ascii_uppercase() => {
+            s.bump();
+            Ok(c)
+        }
+        _ => Err(s.error("a capital letter")),
+    }
+}
+
+fn strs(strings: &[String]) -> Vec<&str> {
+    strings.iter().map(String::as_str).collect()
+}
+
+impl Game {
+    pub fn parse(rules: &str) -> Result<Self, ParseError> {
+        let mut shapes = Vec::<Shape>::new();
+        let mut outcomes = [None; 3];
+
+        for (i, line) in rules.lines().enumerate() {
+            let mut s = Scanner::at_line(line, i + 1);
+            if line.trim().is_empty() || s.eat("#") {
+                continue;
+            }
+
+            match s.choice(&["move ", "outcome "])? {
+                "move " => {
+                    let name = s.word()?.to_string();
+                    s.expect(" ")?;
+                    let at = s.clone();
+                    let theirs = letter(&mut s)?;
+                    if shapes.iter().any(|shape| shape.theirs == theirs) {
+                        return Err(at.error("a letter of no other move"));
+                    }
+                    s.expect(" ")?;
+                    let at = s.clone();
+                    let ours = letter(&mut s)?;
+                    if shapes.iter().any(|shape| shape.ours == ours) {
+                        return Err(at.error("a letter of no other move"));
+                    }
+                    s.expect(" ")?;
+                    let score = s.number_in(0, MAX_POINTS)?;
+                    shapes.push(Shape {
+                        name,
+                        theirs,
+                        ours,
+                        score,
+                    });
+                }
+                _ => {
+                    let at = s.clone();
+                    let name = s.choice(&OUTCOMES.map(|(name, _)| name))?;
+                    let k = OUTCOMES.iter().position(|&(n, _)| n == name).unwrap();
+                    if outcomes[k].is_some() {
+                        return Err(at.error("an outcome not given yet"));
+                    }
+                    s.expect(" ")?;
+                    let at = s.clone();
+                    let code = letter(&mut s)?;
+                    if outcomes.iter().flatten().any(|&(c, _)| c == code) {
+                        return Err(at.error("a letter of no other outcome"));
+                    }
+                    s.expect(" ")?;
+                    outcomes[k] = Some((code, s.number_in(0, MAX_POINTS)?));
+                }
+            }
+            s.finish()?;
+        }
+
+        if shapes.len() < 3 || shapes.len().is_multiple_of(2) {
+            return Err(parse::missing(rules, "an odd number of moves, at least 3"));
+        }
+        let [Some(lose), Some(draw), Some(win)] = outcomes else {
+            return Err(parse::missing(
+                rules,
+                "the points of losing, drawing and winning",
+            ));
+        };
+
+        Ok(Self {
+            shapes,
+            outcomes: [lose, draw, win],
+        })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::parse(ROCK_PAPER_SCISSORS).unwrap()
+    }
+
+    pub fn moves(&self) -> impl DoubleEndedIterator<Item = Move> {
+        (0..self.shapes.len()).map(Move)
+    }
+
+    pub fn name(&self, m: Move) -> &str {
+        &self.shapes[m.0].name
+    }
+
+    // how the round ends for us
+    pub fn outcome(&self, theirs: Move, ours: Move) -> Outcome {
+        let n = self.shapes.len();
+        match (ours.0 + n - theirs.0) % n {
+            0 => Outcome::Draw,
+            d if d % 2 == 1 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    // the points of the shape we played plus those of the outcome
+    pub fn score(&self, theirs: Move, ours: Move) -> u32 {
+        self.shapes[ours.0].score + self.outcomes[self.outcome(theirs, ours) as usize].1
+    }
+
+    // the move ending the round as asked, the best scoring if there are several
+    pub fn reply(&self, theirs: Move, outcome: Outcome) -> Move {
+        self.moves()
+            .filter(|&ours| self.outcome(theirs, ours) == outcome)
+            .max_by_key(|&ours| self.shapes[ours.0].score)
+            .unwrap()
+    }
+
+    // the move scoring the most against theirs, the first of them on ties
+    pub fn best_reply(&self, theirs: Move) -> Move {
+        self.moves()
+            .rev()
+            .max_by_key(|&ours| self.score(theirs, ours))
+            .unwrap()
+    }
+
+    // the score of a round when both sides pick their move at random, with
+    // the chances given by move. None unless both are a chance per move adding up to 1
+    pub fn expected_score(&self, theirs: &[f64], ours: &[f64]) -> Option<f64> {
+        if !self.is_strategy(theirs) || !self.is_strategy(ours) {
+            return None;
+        }
+
+        let score = self
+            .moves()
+            .flat_map(|t| self.moves().map(move |o| (t, o)))
+            .map(|(t, o)| theirs[t.0] * ours[o.0] * self.score(t, o) as f64)
+            .sum();
+        Some(score)
+    }
+
+    fn is_strategy(&self, chances: &[f64]) -> bool {
+        chances.len() == self.shapes.len()
+            && chances.iter().all(|&p| p >= 0.0)
+            && (chances.iter().sum::<f64>() - 1.0).abs() < 1e-9
+    }
+
+    // what the second letter of a round means, in the first interpretation
+    pub fn as_move(&self, code: char) -> Option<Move> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.ours == code)
+            .map(Move)
+    }
+
+    // and in the second one
+    pub fn as_outcome(&self, code: char) -> Option<Outcome> {
+        let k = self.outcomes.iter().position(|&(c, _)| c == code)?;
+        Some(OUTCOMES[k].1)
+    }
+
+    // a line per round, with the letters of the game
+    pub fn parse_guide(self, input: &str) -> Result<Guide, ParseError> {
+        let mut codes = self
+            .shapes
+            .iter()
+            .map(|shape| shape.ours)
+            .collect::<Vec<_>>();
+        codes.extend(self.outcomes.iter().map(|&(c, _)| c));
+        codes.sort_unstable();
+        codes.dedup();
+
+        let theirs = self.shapes.iter().map(|shape| shape.theirs.to_string());
+        let theirs = theirs.collect::<Vec<_>>();
+        let codes = codes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        let (theirs, codes) = (strs(&theirs), strs(&codes));
+
+        let rounds = parse::lines(input, |s| {
+            let letter = s.choice(&theirs)?;
+            s.expect(" ")?;
+            let code = s.choice(&codes)?;
+            Ok(Round {
+                theirs: Move(theirs.iter().position(|&l| l == letter).unwrap()),
+                code: code.chars().next().unwrap(),
+            })
+        })?;
+
+        Ok(Guide { game: self, rounds })
+    }
+}
+
+// what the opponent column gives away, and what could be scored against it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Analysis {
+    pub rounds: usize,
+    // how often the opponent plays every move, by move
+    pub frequencies: Vec<usize>,
+    // the best reply to every move of theirs and its score
+    pub best_replies: Vec<(Move, u32)>,
+    // the single move that scores the most played every round, and its total
+    pub best_fixed: (Move, u64),
+    // the total of always playing the best reply
+    pub best_total: u64,
+    // the totals of the two interpretations of the guide
+    pub as_moves: Option<u64>,
+    pub as_outcomes: Option<u64>,
+    // the expected total of playing every move at random with the same chance
+    pub uniform: f64,
+}
+
+impl Analysis {
+    pub fn report(&self, game: &Game) -> String {
+        let mut res = format!("rounds: {}\n", self.rounds);
+
+        res.push_str("their moves:\n");
+        for (m, &n) in game.moves().zip(&self.frequencies) {
+            let share = n as f64 * 100.0 / self.rounds.max(1) as f64;
+            let (reply, score) = self.best_replies[m.0];
+            res.push_str(&format!(
+                "  {}: {} ({:.1}%), best reply {} for {}\n",
+                game.name(m),
+                n,
+                share,
+                game.name(reply),
+                score
+            ));
+        }
+
+        let total = |total: Option<u64>| total.map_or("-".to_string(), |t| t.to_string());
+        let (fixed, fixed_total) = self.best_fixed;
+        res.push_str(&format!("always {}: {}\n", game.name(fixed), fixed_total));
+        res.push_str(&format!("best replies: {}\n", self.best_total));
+        res.push_str(&format!("at random: {:.1}\n", self.uniform));
+        res.push_str(&format!("guide as moves: {}\n", total(self.as_moves)));
+        res.push_str(&format!("guide as outcomes: {}\n", total(self.as_outcomes)));
+        res
+    }
+}
+
+impl Guide {
+    // how often the opponent plays every move, by move
+    pub fn frequencies(&self) -> Vec<usize> {
+        let mut res = vec![0; self.game.moves().count()];
+        for round in &self.rounds {
+            res[round.theirs.0] += 1;
+        }
+        res
+    }
+
+    // the expected total over the guide of playing at random with the given
+    // chance of every move, against the moves of theirs as they come
+    pub fn expected_total(&self, strategy: &[f64]) -> Option<f64> {
+        if self.rounds.is_empty() {
+            return self.game.is_strategy(strategy).then_some(0.0);
+        }
+
+        let rounds = self.rounds.len() as f64;
+        let theirs = self
+            .frequencies()
+            .iter()
+            .map(|&n| n as f64 / rounds)
+            .collect::<Vec<_>>();
+        Some(self.game.expected_score(&theirs, strategy)? * rounds)
+    }
+
+    pub fn analyze(&self) -> Analysis {
+        let game = &self.game;
+        let frequencies = self.frequencies();
+
+        let best_replies = game
+            .moves()
+            .map(|theirs| {
+                let reply = game.best_reply(theirs);
+                (reply, game.score(theirs, reply))
+            })
+            .collect::<Vec<_>>();
+        let best_total = game
+            .moves()
+            .map(|m| frequencies[m.0] as u64 * best_replies[m.0].1 as u64)
+            .sum();
+
+        let fixed_total = |ours: Move| {
+            game.moves()
+                .map(|theirs| frequencies[theirs.0] as u64 * game.score(theirs, ours) as u64)
+                .sum::<u64>()
+        };
+        let fixed = game.moves().rev().max_by_key(|&m| fixed_total(m)).unwrap();
+
+        let n = frequencies.len();
+        Analysis {
+            rounds: self.rounds.len(),
+            best_replies,
+            best_fixed: (fixed, fixed_total(fixed)),
+            best_total,
+            as_moves: self.total_as_moves(),
+            as_outcomes: self.total_as_outcomes(),
+            uniform: self.expected_total(&vec![1.0 / n as f64; n]).unwrap(),
+            frequencies,
+        }
+    }
+
+    // the second letter is the move to play, or None if a letter is no move
+    pub fn total_as_moves(&self) -> Option<u64> {
+        self.rounds
+            .iter()
+            .map(|r| Some(self.game.score(r.theirs, self.game.as_move(r.code)?) as u64))
+            .sum()
+    }
+
+    // the second letter is how the round should end
+    pub fn total_as_outcomes(&self) -> Option<u64> {
+        self.rounds
+            .iter()
+            .map(|r| {
+                let ours = self.game.reply(r.theirs, self.game.as_outcome(r.code)?);
+                Some(self.game.score(r.theirs, ours) as u64)
+            })
+            .sum()
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    type Input = Guide;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Game::rock_paper_scissors().parse_guide(input)
+    }
+
+    // every letter means both a move and an outcome in rock paper scissors
+    fn part1(guide: &Self::Input) -> String {
+        guide.total_as_moves().unwrap().to_string()
+    }
+
+    fn part2(guide: &Self::Input) -> Option<String> {
+        Some(guide.total_as_outcomes().unwrap().to_string())
+    }
+
+    // size rounds
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.pick(&["A", "B", "C"]),
+                    rng.pick(&["X", "Y", "Z"])
+                )
+            })
+            .collect()
+    }
+}
@@ -1,64 +1,92 @@
-use std::fs;
-use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
 
-fn score(p1: &str, p2: &str) -> i32 {
-    let piece = match p2 {
-        "A" => 1,
-        "B" => 2,
-        "C" => 3,
-        _ => unreachable!(),
-    };
+use aoc_common::input::{self, InputArgs};
+use aoc_common::output::{self, Format};
+use aoc_common::{solution, Answer, Solution};
+use clap::Parser;
+use day2::{Day2, Game};
 
-    let result = match (p1 == p2, p1, p2) {
-        (true, _, _) => 3,
-        (false, "A", "B") => 6,
-        (false, "B", "C") => 6,
-        (false, "C", "A") => 6,
-        (false, _, _) => 0,
-    };
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
 
-    return piece + result;
-}
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
 
-fn choice1(p2: &str) -> &str {
-    match p2 {
-        "X" => "A",
-        "Y" => "B",
-        "Z" => "C",
-        _ => unreachable!(),
-    }
+    /// Play the game of this rules file instead of rock paper scissors (see day2/rules)
+    #[arg(long, value_name = "PATH")]
+    rules: Option<PathBuf>,
+
+    /// Report what the opponent plays and what could be scored against it instead of the answers
+    #[arg(long)]
+    analyze: bool,
 }
 
-fn choice2<'a>(p1: &'a str, p2: &'a str) -> &'a str {
-    match (p1, p2) {
-        (_, "Y") => p1,
+fn load_rules(path: &Path) -> Option<Game> {
+    let rules = match input::read(path) {
+        Ok(rules) => rules,
+        Err(err) => {
+            eprintln!("error: could not read {}: {}", path.display(), err);
+            return None;
+        }
+    };
 
-        ("A", "X") => "C",
-        ("A", "Z") => "B",
+    match Game::parse(&rules) {
+        Ok(game) => Some(game),
+        Err(err) => {
+            eprintln!("error: {}: {}", path.display(), err.report(&rules));
+            None
+        }
+    }
+}
 
-        ("B", "X") => "A",
-        ("B", "Z") => "C",
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let game = match &cli.rules {
+        Some(path) => match load_rules(path) {
+            Some(game) => game,
+            None => return ExitCode::FAILURE,
+        },
+        None if cli.analyze => Game::rock_paper_scissors(),
+        None => return solution::run::<Day2>(&cli.input, cli.format),
+    };
 
-        ("C", "X") => "B",
-        ("C", "Z") => "A",
+    let guide = match solution::load(&cli.input, |content| game.parse_guide(content)) {
+        Ok(guide) => guide,
+        Err(code) => return code,
+    };
 
-        _ => unreachable!(),
+    if cli.analyze {
+        print!("{}", guide.analyze().report(&guide.game));
+        return ExitCode::SUCCESS;
     }
-}
 
-fn main() {
-    let reader = io::BufReader::new(fs::File::open("./input").unwrap());
-    let (res1, res2) = reader
-        .lines()
-        .map(|l| l.unwrap())
-        .fold((0, 0), |acc, line| {
-            let (p1, p2) = line.split_once(" ").unwrap();
-            (
-                acc.0 + score(p1, choice1(p2)),
-                acc.1 + score(p1, choice2(p1, p2)),
-            )
-        });
+    // a letter can be a move but not an outcome, or the other way around,
+    // which leaves that reading of the guide without an answer
+    let mut answers = Vec::new();
+    for part in 1..=2 {
+        let start = Instant::now();
+        let total = match part {
+            1 => guide.total_as_moves(),
+            _ => guide.total_as_outcomes(),
+        };
+        match total {
+            Some(total) => answers.push(Answer {
+                part,
+                value: total.to_string(),
+                elapsed: start.elapsed(),
+            }),
+            None => eprintln!(
+                "part {}: the guide has letters these rules give no meaning",
+                part
+            ),
+        }
+    }
+    output::print(Day2::DAY, &answers, cli.format);
 
-    println!("1: {}", res1);
-    println!("2: {}", res2);
+    ExitCode::SUCCESS
 }
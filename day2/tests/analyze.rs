@@ -0,0 +1,88 @@
+use aoc_common::Solution;
+use day2::{Day2, Game, Move, ROCK_PAPER_SCISSORS, ROCK_PAPER_SCISSORS_LIZARD_SPOCK};
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn analysis_of_the_example() {
+    let guide = Day2::parse(EXAMPLE).unwrap();
+    let analysis = guide.analyze();
+
+    assert_eq!(analysis.rounds, 3);
+    assert_eq!(analysis.frequencies, [1, 1, 1]);
+    assert_eq!(
+        analysis.best_replies,
+        [(Move(1), 8), (Move(2), 9), (Move(0), 7)]
+    );
+    assert_eq!(analysis.best_fixed, (Move(2), 18));
+    assert_eq!(analysis.best_total, 24);
+    assert_eq!(
+        (analysis.as_moves, analysis.as_outcomes),
+        (Some(15), Some(12))
+    );
+    assert_eq!(analysis.uniform, 15.0);
+}
+
+#[test]
+fn best_replies_beat_both_readings() {
+    let input = Day2::generate(&mut aoc_common::random::Rng::new(7), 500);
+    let analysis = Day2::parse(&input).unwrap().analyze();
+
+    assert_eq!(analysis.frequencies.iter().sum::<usize>(), 500);
+    assert!(analysis.as_moves.unwrap() <= analysis.best_total);
+    assert!(analysis.as_outcomes.unwrap() <= analysis.best_total);
+    assert!(analysis.best_fixed.1 <= analysis.best_total);
+}
+
+#[test]
+fn expected_scores_of_mixed_strategies() {
+    let guide = Game::parse(ROCK_PAPER_SCISSORS_LIZARD_SPOCK)
+        .unwrap()
+        .parse_guide("A V\nA V\nB W\nC X\n")
+        .unwrap();
+
+    // always spock against half rock, a quarter paper and a quarter scissors
+    let spock = [0.0, 0.0, 0.0, 1.0, 0.0];
+    let total = guide.expected_total(&spock).unwrap();
+    assert_eq!(total, 4.0 * (4.0 + 0.75 * 6.0));
+    assert_eq!(total, guide.analyze().best_fixed.1 as f64);
+
+    // a mix is the mix of the totals of its moves
+    let half = [0.5, 0.0, 0.0, 0.5, 0.0];
+    let rock = [1.0, 0.0, 0.0, 0.0, 0.0];
+    let mixed = (guide.expected_total(&rock).unwrap() + total) / 2.0;
+    assert_eq!(guide.expected_total(&half), Some(mixed));
+}
+
+#[test]
+fn strategies_are_a_chance_per_move() {
+    let guide = Day2::parse(EXAMPLE).unwrap();
+    let game = &guide.game;
+
+    assert_eq!(guide.expected_total(&[1.0, 0.0]), None);
+    assert_eq!(guide.expected_total(&[0.5, 0.0, 0.0]), None);
+    assert_eq!(guide.expected_total(&[1.5, -0.5, 0.0]), None);
+    assert_eq!(game.expected_score(&[1.0, 0.0, 0.0], &[0.0, 1.0]), None);
+    assert_eq!(
+        game.expected_score(&[1.0, 0.0, 0.0], &[0.0, 1.0, 0.0]),
+        Some(8.0)
+    );
+
+    let empty = Game::rock_paper_scissors().parse_guide("").unwrap();
+    assert_eq!(empty.expected_total(&[0.0, 0.0, 1.0]), Some(0.0));
+    assert_eq!(empty.analyze().uniform, 0.0);
+}
+
+#[test]
+fn totals_of_big_guides() {
+    // past what a u32 holds
+    let rules = ROCK_PAPER_SCISSORS.replace(" 6\n", " 1000000\n");
+    let guide = Game::parse(&rules)
+        .unwrap()
+        .parse_guide(&"C X\n".repeat(5000))
+        .unwrap();
+    let analysis = guide.analyze();
+    assert_eq!(analysis.best_total, 1_000_001 * 5000);
+    assert_eq!(analysis.best_fixed, (Move(0), 1_000_001 * 5000));
+    assert_eq!(analysis.as_moves, Some(1_000_001 * 5000));
+}
@@ -0,0 +1,98 @@
+use day2::{Game, Move, Outcome, ROCK_PAPER_SCISSORS, ROCK_PAPER_SCISSORS_LIZARD_SPOCK};
+
+const EXAMPLE: &str = include_str!("../example");
+
+fn by_name(game: &Game, name: &str) -> Move {
+    game.moves().find(|&m| game.name(m) == name).unwrap()
+}
+
+#[test]
+fn rock_paper_scissors() {
+    let game = Game::parse(ROCK_PAPER_SCISSORS).unwrap();
+    let [rock, paper, scissors] = ["rock", "paper", "scissors"].map(|n| by_name(&game, n));
+
+    assert_eq!(game.outcome(rock, paper), Outcome::Win);
+    assert_eq!(game.outcome(paper, scissors), Outcome::Win);
+    assert_eq!(game.outcome(scissors, rock), Outcome::Win);
+    assert_eq!(game.outcome(paper, rock), Outcome::Lose);
+    assert_eq!(game.outcome(scissors, scissors), Outcome::Draw);
+    assert_eq!(game.score(rock, paper), 8);
+    assert_eq!(game.reply(rock, Outcome::Lose), scissors);
+}
+
+#[test]
+fn every_move_of_rpsls_beats_two_others() {
+    let game = Game::parse(ROCK_PAPER_SCISSORS_LIZARD_SPOCK).unwrap();
+    let wins = [
+        ("scissors", "paper"),
+        ("paper", "rock"),
+        ("rock", "lizard"),
+        ("lizard", "spock"),
+        ("spock", "scissors"),
+        ("scissors", "lizard"),
+        ("lizard", "paper"),
+        ("paper", "spock"),
+        ("spock", "rock"),
+        ("rock", "scissors"),
+    ];
+
+    for (winner, loser) in wins {
+        let (winner, loser) = (by_name(&game, winner), by_name(&game, loser));
+        assert_eq!(game.outcome(loser, winner), Outcome::Win);
+        assert_eq!(game.outcome(winner, loser), Outcome::Lose);
+    }
+}
+
+#[test]
+fn both_readings_of_the_example_with_rpsls() {
+    let guide = Game::parse(ROCK_PAPER_SCISSORS_LIZARD_SPOCK)
+        .unwrap()
+        .parse_guide(EXAMPLE)
+        .unwrap();
+    assert_eq!(guide.total_as_moves(), Some(24));
+    // losing to paper can be done with rock or spock, spock scores more
+    assert_eq!(guide.total_as_outcomes(), Some(18));
+
+    // V is a move but not an outcome
+    let guide = Game::parse(ROCK_PAPER_SCISSORS_LIZARD_SPOCK)
+        .unwrap()
+        .parse_guide("A V\n")
+        .unwrap();
+    assert_eq!(guide.total_as_moves(), Some(4));
+    assert_eq!(guide.total_as_outcomes(), None);
+}
+
+#[test]
+fn bad_rules() {
+    let outcomes = "outcome lose X 0\noutcome draw Y 3\noutcome win Z 6\n";
+    let even = format!("move a A X 1\nmove b B Y 2\n{}", outcomes);
+    assert!(Game::parse(&even).is_err());
+
+    let twice = format!("move a A X 1\nmove b A Y 2\nmove c C Z 3\n{}", outcomes);
+    let err = Game::parse(&twice).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 8));
+
+    let again = format!(
+        "move a A X 1\nmove b B Y 2\nmove c C Z 3\n{}outcome lose W 1\n",
+        outcomes
+    );
+    let err = Game::parse(&again).unwrap_err();
+    assert_eq!((err.line, err.column), (7, 9));
+
+    let huge = format!(
+        "move a A X 4294967295\nmove b B Y 2\nmove c C Z 3\n{}",
+        outcomes
+    );
+    let err = Game::parse(&huge).unwrap_err();
+    assert_eq!((err.line, err.column), (1, 12));
+
+    let no_win = "move a A X 1\nmove b B Y 2\nmove c C Z 3\noutcome lose X 0\noutcome draw Y 3\n";
+    assert!(Game::parse(no_win).is_err());
+}
+
+#[test]
+fn guide_letters_come_from_the_rules() {
+    let game = Game::parse(ROCK_PAPER_SCISSORS).unwrap();
+    let err = game.parse_guide("A X\nD Y\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+}
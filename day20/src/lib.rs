@@ -0,0 +1,84 @@
+use aoc_common::parse;
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+
+// far enough from overflowing once multiplied by the decryption key
+const LIMIT: i64 = 1_000_000_000;
+
+fn next_index(i: i64, val: i64, n: i64) -> i64 {
+    if i + val > 0 {
+        (i + val) % n
+    } else {
+        n + (i + val) % n
+    }
+}
+
+fn solve(values: &[i64], rounds: i32, key: i64) -> i64 {
+    let n = values.len();
+
+    let mut xs = values
+        .iter()
+        .copied()
+        .map(|x| x * key)
+        .enumerate()
+        .collect::<Vec<_>>();
+
+    for _ in 0..rounds {
+        for k in 0..n {
+            let (i, &v) = xs.iter().enumerate().find(|e| e.1.0 == k).unwrap();
+
+            let j = next_index(i as i64, v.1, (n - 1) as i64) as usize;
+            if j > i {
+                xs.copy_within(i + 1..=j, i);
+            } else {
+                xs.copy_within(j..i, j + 1);
+            }
+            xs[j] = v;
+        }
+    }
+
+    let offset = xs.iter().enumerate().find(|(_, v)| v.1 == 0).unwrap().0;
+    [1000, 2000, 3000]
+        .iter()
+        .map(|i| xs[(i + offset) % n].1)
+        .sum::<i64>()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let values = parse::lines(input, |s| s.number_in(-LIMIT, LIMIT))?;
+        if !values.contains(&0) {
+            return Err(parse::missing(input, "a value of 0"));
+        }
+        // a number moves among the others, so there has to be another one
+        if values.len() < 2 {
+            return Err(parse::missing(input, "a second value"));
+        }
+
+        Ok(values)
+    }
+
+    fn part1(values: &Self::Input) -> String {
+        solve(values, 1, 1).to_string()
+    }
+
+    fn part2(values: &Self::Input) -> Option<String> {
+        Some(solve(values, 10, 811589153).to_string())
+    }
+
+    // size numbers, at least two and only one of them 0
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut values = (1..size.max(2))
+            .map(|_| rng.range(1, 10000) * if rng.chance(0.5) { 1 } else { -1 })
+            .collect::<Vec<_>>();
+        values.insert(rng.index(values.len() + 1), 0);
+
+        values.iter().map(|value| format!("{}\n", value)).collect()
+    }
+}
@@ -0,0 +1,38 @@
+use aoc_common::Solution;
+use day20::Day20;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day20::parse(EXAMPLE).unwrap();
+    assert_eq!(Day20::part1(&input), "3");
+}
+
+#[test]
+fn part2() {
+    let input = Day20::parse(EXAMPLE).unwrap();
+    assert_eq!(Day20::part2(&input).unwrap(), "1623178306");
+}
+
+#[test]
+fn a_single_number_is_an_error() {
+    assert!(Day20::parse("0\n").is_err());
+    // every thousandth number after 0 is 0 again
+    let input = Day20::parse("0\n5\n").unwrap();
+    assert_eq!(Day20::part1(&input), "0");
+}
+
+#[test]
+fn numbers_that_overflow_with_the_key_are_errors() {
+    assert!(Day20::parse("0\n1000000000\n").is_ok());
+    assert!(Day20::parse("0\n-1000000001\n").is_err());
+}
+
+#[test]
+fn generated_inputs_have_two_numbers() {
+    for size in 0..3 {
+        let input = Day20::generate(&mut aoc_common::random::Rng::new(1), size);
+        assert!(Day20::parse(&input).is_ok(), "size {}", size);
+    }
+}
@@ -0,0 +1,326 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Debug)]
+pub enum Yell {
+    Number(i64),
+    Expr(String, String, char),
+}
+
+type Monkeys = HashMap<String, Yell>;
+
+fn parse_name<'a>(s: &mut Scanner<'a>) -> parse::Result<&'a str> {
+    let name = s.take_while(|c| c.is_ascii_lowercase());
+    if name.is_empty() {
+        return Err(s.error("a monkey name"));
+    }
+    Ok(name)
+}
+
+// references to other monkeys are kept along with which monkey made them and where
+fn parse_yell<'a>(
+    s: &mut Scanner<'a>,
+    monkey: &'a str,
+    refs: &mut Vec<(&'a str, &'a str, Scanner<'a>)>,
+) -> parse::Result<Yell> {
+    if s.peek().is_some_and(|c| c == '-' || c.is_ascii_digit()) {
+        return Ok(Yell::Number(s.number()?));
+    }
+
+    let at = s.clone();
+    let a = parse_name(s)?;
+    refs.push((monkey, a, at));
+    s.expect(" ")?;
+    let op = s.choice(&["+", "-", "*", "/"])?;
+    s.expect(" ")?;
+    let at = s.clone();
+    let b = parse_name(s)?;
+    refs.push((monkey, b, at));
+
+    let op = op.chars().next().unwrap();
+    Ok(Yell::Expr(a.to_string(), b.to_string(), op))
+}
+
+// where a monkey first waits on itself, through any number of others
+fn find_cycle<'r, 'a>(refs: &'r [(&'a str, &'a str, Scanner<'a>)]) -> Option<&'r Scanner<'a>> {
+    let mut waits = HashMap::<&str, Vec<usize>>::new();
+    for (i, &(monkey, _, _)) in refs.iter().enumerate() {
+        waits.entry(monkey).or_default().push(i);
+    }
+
+    // false while a monkey is on the stack, true once all it waits on is done
+    let mut done = HashMap::new();
+    for &(start, _, _) in refs {
+        if done.contains_key(start) {
+            continue;
+        }
+        done.insert(start, false);
+        let mut stack = vec![(start, 0)];
+
+        while let Some(&(monkey, k)) = stack.last() {
+            let Some(&r) = waits.get(monkey).and_then(|w| w.get(k)) else {
+                done.insert(monkey, true);
+                stack.pop();
+                continue;
+            };
+            stack.last_mut().unwrap().1 += 1;
+
+            let (_, next, at) = &refs[r];
+            match done.get(next) {
+                Some(false) => return Some(at),
+                Some(true) => {}
+                None => {
+                    done.insert(next, false);
+                    stack.push((next, 0));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// a op b, unless that divides by zero or overflows
+fn apply(a: i64, op: char, b: i64) -> Option<i64> {
+    match op {
+        '+' => a.checked_add(b),
+        '-' => a.checked_sub(b),
+        '*' => a.checked_mul(b),
+        '/' => a.checked_div(b),
+        _ => unreachable!(),
+    }
+}
+
+// what key yells, None if that depends on what me yells and an error if
+// working it out divides by zero or overflows
+fn value(monkeys: &Monkeys, key: &str, me: Option<&str>) -> Result<Option<i64>, ()> {
+    if me == Some(key) {
+        return Ok(None);
+    }
+
+    match &monkeys[key] {
+        Yell::Number(x) => Ok(Some(*x)),
+        Yell::Expr(a, b, op) => match (value(monkeys, a, me)?, value(monkeys, b, me)?) {
+            (Some(a), Some(b)) => apply(a, *op, b).map(Some).ok_or(()),
+            _ => Ok(None),
+        },
+    }
+}
+
+// what me has to yell for key to yell res, if some whole number does it
+fn solve(monkeys: &Monkeys, key: &str, res: i64, me: &str) -> Option<i64> {
+    if key == me {
+        return Some(res);
+    }
+    let Yell::Expr(a, b, op) = &monkeys[key] else {
+        return None;
+    };
+
+    let val_a = value(monkeys, a, Some(me)).ok()?;
+    let val_b = value(monkeys, b, Some(me)).ok()?;
+
+    match (val_a, op, val_b) {
+        (Some(x), '+', None) => solve(monkeys, b, res.checked_sub(x)?, me),
+        (None, '+', Some(x)) => solve(monkeys, a, res.checked_sub(x)?, me),
+
+        (Some(x), '*', None) if res.checked_rem(x)? == 0 => solve(monkeys, b, res / x, me),
+        (None, '*', Some(x)) if res.checked_rem(x)? == 0 => solve(monkeys, a, res / x, me),
+
+        (Some(x), '-', None) => solve(monkeys, b, x.checked_sub(res)?, me),
+        (None, '-', Some(x)) => solve(monkeys, a, x.checked_add(res)?, me),
+
+        // division rounds, so only some of the values that could do are tried
+        (Some(x), '/', None) => {
+            let y = x.checked_div(res)?;
+            if x.checked_div(y)? != res {
+                return None;
+            }
+            solve(monkeys, b, y, me)
+        }
+        (None, '/', Some(x)) if x != 0 => solve(monkeys, a, res.checked_mul(x)?, me),
+
+        _ => None,
+    }
+}
+
+// how big the generated values may get, far from overflowing when added up
+const LIMIT: i64 = 1 << 40;
+
+struct Generator {
+    names: HashSet<String>,
+    lines: Vec<String>,
+}
+
+impl Generator {
+    // a four letter name not taken yet
+    fn name(&mut self, rng: &mut Rng) -> String {
+        loop {
+            let name = (0..4).map(|_| rng.char_in('a', 'z')).collect::<String>();
+            if self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    // a monkey whose value comes from size monkeys in all, none of them humn,
+    // with its name and that value
+    fn constant(&mut self, rng: &mut Rng, size: usize) -> (String, i64) {
+        let name = self.name(rng);
+        if size < 3 {
+            let value = rng.range(1, 20);
+            self.lines.push(format!("{}: {}", name, value));
+            return (name, value);
+        }
+
+        let left = rng.range(1, size as i64 - 2) as usize;
+        let (a, x) = self.constant(rng, left);
+        let (b, y) = self.constant(rng, size - 1 - left);
+
+        let mut ops = vec![('+', x + y), ('-', x - y)];
+        if let Some(product) = x.checked_mul(y).filter(|p| p.abs() < LIMIT) {
+            ops.push(('*', product));
+        }
+        if y != 0 && x % y == 0 {
+            ops.push(('/', x / y));
+        }
+
+        let (op, value) = *rng.pick(&ops);
+        self.lines.push(format!("{}: {} {} {}", name, a, op, b));
+        (name, value)
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+
+    type Input = Monkeys;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut refs = Vec::new();
+        let monkeys = parse::lines(input, |s| {
+            let monkey = parse_name(s)?;
+            s.expect(": ")?;
+            Ok((monkey.to_string(), parse_yell(s, monkey, &mut refs)?))
+        })?
+        .into_iter()
+        .collect::<Monkeys>();
+
+        for (_, name, at) in &refs {
+            if !monkeys.contains_key(*name) {
+                return Err(at.error("a monkey defined in the input"));
+            }
+        }
+        if let Some(at) = find_cycle(&refs) {
+            return Err(at.error("a monkey that does not wait on itself"));
+        }
+
+        match monkeys.get("root") {
+            Some(Yell::Expr(..)) => {}
+            _ => return Err(parse::missing(input, "a root monkey with an operation")),
+        }
+        if !monkeys.contains_key("humn") {
+            return Err(parse::missing(input, "a humn monkey"));
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> String {
+        match value(monkeys, "root", None) {
+            Ok(Some(x)) => x.to_string(),
+            _ => "undefined".to_string(),
+        }
+    }
+
+    // exactly one side of root has to depend on humn
+    fn part2(monkeys: &Self::Input) -> Option<String> {
+        let Yell::Expr(a, b, _) = &monkeys["root"] else {
+            unreachable!()
+        };
+
+        let humn = Some("humn");
+        let answer = match (value(monkeys, a, humn), value(monkeys, b, humn)) {
+            (Ok(None), Ok(Some(x))) => solve(monkeys, a, x, "humn"),
+            (Ok(Some(x)), Ok(None)) => solve(monkeys, b, x, "humn"),
+            _ => None,
+        };
+        Some(answer.map_or_else(|| "none".to_string(), |x| x.to_string()))
+    }
+
+    // about size monkeys. humn starts a chain of operations with other
+    // monkeys on one side of root, which the other side is then made to match
+    // for another value of humn, so that both parts have whole answers
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut gen = Generator {
+            names: HashSet::from(["root".to_string(), "humn".to_string()]),
+            lines: Vec::new(),
+        };
+
+        let steps = (size / 8).max(1);
+        let (yell, answer) = (rng.range(1, 5000), rng.range(1, 5000));
+        gen.lines.push(format!("humn: {}", yell));
+
+        // the chain, worked out both for what humn yells and for the answer
+        let (mut chain, mut values) = ("humn".to_string(), (yell, answer));
+        for _ in 0..steps {
+            let (other, c) = gen.constant(rng, 3);
+
+            let small = |v: i64| v.checked_mul(c).is_some_and(|v| v.abs() < LIMIT);
+            let mut ops = vec!['+', '-', '<'];
+            // times zero would make whatever humn yells do
+            if c != 0 && small(values.0) && small(values.1) {
+                ops.push('*');
+            }
+            if c != 0 && values.0 % c == 0 && values.1 % c == 0 {
+                ops.push('/');
+            }
+
+            let name = gen.name(rng);
+            let (line, next) = match *rng.pick(&ops) {
+                '+' => (
+                    format!("{}: {} + {}", name, chain, other),
+                    (values.0 + c, values.1 + c),
+                ),
+                '-' => (
+                    format!("{}: {} - {}", name, chain, other),
+                    (values.0 - c, values.1 - c),
+                ),
+                '<' => (
+                    format!("{}: {} - {}", name, other, chain),
+                    (c - values.0, c - values.1),
+                ),
+                '*' => (
+                    format!("{}: {} * {}", name, other, chain),
+                    (values.0 * c, values.1 * c),
+                ),
+                _ => (
+                    format!("{}: {} / {}", name, chain, other),
+                    (values.0 / c, values.1 / c),
+                ),
+            };
+            gen.lines.push(line);
+            (chain, values) = (name, next);
+        }
+
+        // the other side, nudged to what the chain comes to for the answer
+        let (other, w) = gen.constant(rng, size.saturating_sub(steps * 5).max(1));
+        let (nudge, name) = (values.1 - w, gen.name(rng));
+        let k = gen.name(rng);
+        gen.lines.push(format!("{}: {}", k, nudge.abs()));
+        let op = if nudge < 0 { '-' } else { '+' };
+        gen.lines.push(format!("{}: {} {} {}", name, other, op, k));
+
+        if rng.chance(0.5) {
+            gen.lines.push(format!("root: {} + {}", chain, name));
+        } else {
+            gen.lines.push(format!("root: {} + {}", name, chain));
+        }
+
+        rng.shuffle(&mut gen.lines);
+        gen.lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+}
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day21::Day21;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day21::parse(EXAMPLE).unwrap();
+    assert_eq!(Day21::part1(&input), "152");
+}
+
+#[test]
+fn part2() {
+    let input = Day21::parse(EXAMPLE).unwrap();
+    assert_eq!(Day21::part2(&input).unwrap(), "301");
+}
@@ -0,0 +1,47 @@
+use aoc_common::Solution;
+use day21::Day21;
+
+#[test]
+fn monkeys_waiting_on_themselves_are_errors() {
+    let err = Day21::parse("root: a + humn\na: b + b\nb: a * a\nhumn: 1\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 4));
+
+    let err = Day21::parse("root: root + humn\nhumn: 1\n").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 7));
+}
+
+#[test]
+fn dividing_by_zero_is_undefined() {
+    let input = Day21::parse("root: a / b\na: 4\nb: humn - c\nc: 3\nhumn: 3\n").unwrap();
+    assert_eq!(Day21::part1(&input), "undefined");
+    // humn - 3 has to equal a
+    assert_eq!(Day21::part2(&input).unwrap(), "7");
+}
+
+#[test]
+fn riddles_without_a_whole_answer() {
+    // humn on both sides, on neither, and 2 * humn = 5
+    for input in [
+        "root: humn + humn\nhumn: 1\n",
+        "root: a + b\na: 1\nb: 2\nhumn: 1\n",
+        "root: a + b\na: c * humn\nc: 2\nb: 5\nhumn: 1\n",
+    ] {
+        let input = Day21::parse(input).unwrap();
+        assert_eq!(Day21::part2(&input).unwrap(), "none");
+    }
+}
+
+#[test]
+fn overflowing_is_undefined() {
+    let input = Day21::parse("root: a * a\na: 9223372036854775807\nhumn: 1\n").unwrap();
+    assert_eq!(Day21::part1(&input), "undefined");
+}
+
+#[test]
+fn generated_riddles_have_an_answer() {
+    for seed in 0..20 {
+        let input = Day21::generate(&mut aoc_common::random::Rng::new(seed), 500);
+        let input = Day21::parse(&input).unwrap();
+        assert_ne!(Day21::part2(&input).unwrap(), "none", "seed {}", seed);
+    }
+}
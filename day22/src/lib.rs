@@ -0,0 +1,483 @@
+use aoc_common::grid::{Grid, Point};
+use aoc_common::parse::{self, Scanner};
+use aoc_common::point::Dir4;
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+
+mod part1;
+
+const EMPTY: char = ' ';
+const OPEN: char = '.';
+const WALL: char = '#';
+
+// the most tiles a single step of the path can go, as they are walked one by one
+const MAX_FORWARD: usize = 10_000;
+
+pub type Map = Grid<char>;
+pub type Face = Grid<char>;
+type Transform<'a> = Box<dyn Fn(Point) -> Point + 'a>;
+
+// the last part of the password
+fn facing_score(facing: Dir4) -> usize {
+    match facing {
+        Dir4::Right => 0,
+        Dir4::Down => 1,
+        Dir4::Left => 2,
+        Dir4::Up => 3,
+    }
+}
+
+enum FaceType {
+    Front = 0,
+    Top = 1,
+    Right = 2,
+    Bottom = 3,
+    Left = 4,
+    Back = 5,
+}
+
+// the faces of the map folded up so far, turned around so that the one being
+// walked on is always at the front
+#[derive(Default)]
+pub struct Cube {
+    faces: [Option<Face>; 6],
+    size: usize,
+}
+
+impl Cube {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_front(&self) -> Option<&Face> {
+        self.faces[FaceType::Front as usize].as_ref()
+    }
+
+    fn is_open(&self, pos: (usize, usize)) -> bool {
+        self.faces[FaceType::Front as usize].as_ref().unwrap()[pos] == OPEN
+    }
+
+    pub fn set_front(&mut self, face: Face) {
+        self.size = face.height();
+        self.faces[FaceType::Front as usize] = Some(face);
+    }
+
+    // rolls the cube so that the face on the dir side of the front comes to the front
+    pub fn permute(&mut self, dir: Dir4) {
+        let front = self.faces[FaceType::Front as usize].take();
+
+        let mut top = self.faces[FaceType::Top as usize].take();
+        let mut right = self.faces[FaceType::Right as usize].take();
+        let mut bottom = self.faces[FaceType::Bottom as usize].take();
+        let mut left = self.faces[FaceType::Left as usize].take();
+        let mut back = self.faces[FaceType::Back as usize].take();
+
+        match dir {
+            Dir4::Up => {
+                Cube::rotate_face_270(right.as_mut());
+                Cube::rotate_face_90(left.as_mut());
+
+                Cube::rotate_face_180(back.as_mut());
+                Cube::rotate_face_180(bottom.as_mut());
+
+                self.faces = [top, back, right, front, left, bottom];
+            }
+
+            Dir4::Right => {
+                Cube::rotate_face_90(top.as_mut());
+                Cube::rotate_face_270(bottom.as_mut());
+
+                self.faces = [right, top, back, bottom, front, left];
+            }
+
+            Dir4::Down => {
+                Cube::rotate_face_90(right.as_mut());
+                Cube::rotate_face_270(left.as_mut());
+
+                Cube::rotate_face_180(back.as_mut());
+                Cube::rotate_face_180(top.as_mut());
+
+                self.faces = [bottom, front, right, back, left, top];
+            }
+
+            Dir4::Left => {
+                Cube::rotate_face_270(top.as_mut());
+                Cube::rotate_face_90(bottom.as_mut());
+
+                self.faces = [left, top, front, bottom, back, right];
+            }
+        }
+    }
+
+    pub fn permute_back(&mut self, dir: Dir4) {
+        self.permute(dir.reverse());
+    }
+
+    fn rotate_face_90(face: Option<&mut Face>) {
+        if let Some(face) = face {
+            let n = face.height() - 1;
+            Self::transform_face(face, |i, j| (n - j, i));
+        }
+    }
+
+    fn rotate_face_180(face: Option<&mut Face>) {
+        if let Some(face) = face {
+            let n = face.height() - 1;
+            Self::transform_face(face, |i, j| (n - i, n - j));
+        }
+    }
+
+    fn rotate_face_270(face: Option<&mut Face>) {
+        if let Some(face) = face {
+            let n = face.height() - 1;
+            Self::transform_face(face, |i, j| (j, n - i));
+        }
+    }
+
+    fn transform_face<F>(face: &mut Face, transform: F)
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let n = face.height();
+        *face = Grid::from_fn(n, n, |(i, j)| face[transform(i, j)]);
+    }
+}
+
+struct Solver {
+    cube: Cube,
+    current: (usize, usize),
+    facing: Dir4,
+}
+
+impl Solver {
+    fn new(cube: Cube) -> Self {
+        Self {
+            cube,
+            current: (0, 0),
+            facing: Dir4::Right,
+        }
+    }
+
+    fn move_forward(&mut self, count: usize) {
+        let n = self.cube.size;
+        let mut pos = self.current;
+
+        let after_permute: Transform<'_> = match self.facing {
+            Dir4::Up => Box::new(|(_, j): (usize, usize)| (n - 1, j)),
+            Dir4::Down => Box::new(|(_, j): (usize, usize)| (0, j)),
+            Dir4::Right => Box::new(|(i, _): (usize, usize)| (i, 0)),
+            Dir4::Left => Box::new(|(i, _): (usize, usize)| (i, n - 1)),
+        };
+
+        for _ in 0..count {
+            let front = self.cube.get_front().unwrap();
+
+            if let Some(next_pos) = front.step(pos, self.facing.delta()) {
+                if !self.cube.is_open(next_pos) {
+                    break;
+                }
+
+                pos = next_pos;
+            } else {
+                self.cube.permute(self.facing);
+                let next_pos = after_permute(pos);
+
+                if !self.cube.is_open(next_pos) {
+                    self.cube.permute_back(self.facing);
+                    break;
+                }
+
+                pos = next_pos;
+            }
+        }
+
+        self.current = pos;
+    }
+
+    fn rotate_right(&mut self) {
+        self.facing = self.facing.turn_right();
+    }
+
+    fn rotate_left(&mut self) {
+        self.facing = self.facing.turn_left();
+    }
+}
+
+// the n by n face whose top left corner is at (i, j) on the map, if it is on it
+fn parse_face(map: &Map, (i, j): Point, n: usize) -> Option<Face> {
+    if map[(i, j)] == EMPTY {
+        None
+    } else {
+        Some(Grid::from_fn(n, n, |(di, dj)| map[(i + di, j + dj)]))
+    }
+}
+
+fn fold_cube(faces: &Grid<Option<Face>>) -> Cube {
+    let first = faces.position(|f| f.is_some()).unwrap();
+    let mut cube = Cube::new();
+    fold_cube_dfs(&mut faces.clone(), first, &mut cube);
+    cube
+}
+
+fn fold_cube_dfs(faces: &mut Grid<Option<Face>>, pos: Point, cube: &mut Cube) {
+    if let Some(face) = faces[pos].take() {
+        cube.set_front(face);
+
+        for dir in [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right] {
+            if let Some(next) = faces.step(pos, dir.delta()) {
+                cube.permute(dir);
+                fold_cube_dfs(faces, next, cube);
+                cube.permute_back(dir);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Forward(usize),
+    Right,
+    Left,
+}
+
+fn parse_path(s: &mut Scanner) -> parse::Result<Vec<Step>> {
+    let mut path = Vec::new();
+
+    loop {
+        path.push(Step::Forward(s.number_in(0, MAX_FORWARD)?));
+
+        match s.peek() {
+            Some('R') => path.push(Step::Right),
+            Some('L') => path.push(Step::Left),
+            _ => break,
+        }
+        s.bump();
+
+        if s.is_done() {
+            break;
+        }
+    }
+
+    Ok(path)
+}
+
+fn cube_password(map: &Map, path: &[Step]) -> usize {
+    // six faces of n by n tiles
+    let n = (map.cells().filter(|&&c| c != EMPTY).count() / 6).isqrt();
+
+    let mut faces = Grid::from_fn(map.width() / n, map.height() / n, |(i, j)| {
+        parse_face(map, (i * n, j * n), n)
+    });
+
+    let mut solver = Solver::new(fold_cube(&faces));
+
+    for step in path {
+        match step {
+            Step::Forward(count) => solver.move_forward(*count),
+            Step::Right => solver.rotate_right(),
+            Step::Left => solver.rotate_left(),
+        }
+    }
+
+    for ((i, j), maybe) in faces.iter_mut() {
+        let Some(face) = maybe.as_mut() else {
+            continue;
+        };
+        let mut pos = solver.current;
+        let mut facing = solver.facing;
+
+        for _ in 0..4 {
+            if face == solver.cube.get_front().unwrap() {
+                let final_pos = (i * n + pos.0 + 1, j * n + pos.1 + 1);
+
+                return 1000 * final_pos.0 + 4 * final_pos.1 + facing_score(facing);
+            }
+
+            Cube::rotate_face_90(Some(face));
+            facing = facing.turn_left();
+            pos = (n - 1 - pos.1, pos.0);
+        }
+    }
+
+    unreachable!()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Notes {
+    pub map: Map,
+    pub path: Vec<Step>,
+}
+
+// the net turned a quarter to the right
+fn turn(net: &Grid<bool>) -> Grid<bool> {
+    Grid::from_fn(net.height(), net.width(), |(i, j)| {
+        net[(net.height() - 1 - j, i)]
+    })
+}
+
+// the net mirrored left to right
+fn flip(net: &Grid<bool>) -> Grid<bool> {
+    Grid::from_fn(net.width(), net.height(), |(i, j)| {
+        net[(i, net.width() - 1 - j)]
+    })
+}
+
+// which n by n blocks of the map are faces, if the map is made of six of them
+fn faces(map: &Map) -> Option<Grid<bool>> {
+    let tiles = map.cells().filter(|&&c| c != EMPTY).count();
+    let n = (tiles / 6).isqrt();
+    if n == 0
+        || 6 * n * n != tiles
+        || !map.width().is_multiple_of(n)
+        || !map.height().is_multiple_of(n)
+    {
+        return None;
+    }
+
+    let faces = Grid::from_fn(map.width() / n, map.height() / n, |(i, j)| {
+        map[(i * n, j * n)] != EMPTY
+    });
+    map.iter()
+        .all(|((i, j), &c)| (c != EMPTY) == faces[(i / n, j / n)])
+        .then_some(faces)
+}
+
+// whether the faces are one of the nets, turned and flipped any which way
+fn folds_into_cube(faces: &Grid<bool>) -> bool {
+    NETS.iter().any(|net| {
+        let mut net = Grid::parse(net, "a face", |c| Some(c == '#')).unwrap();
+        (0..4).any(|_| {
+            net = turn(&net);
+            net == *faces || flip(&net) == *faces
+        })
+    })
+}
+
+// the 11 ways a cube unfolds, up to turning and flipping them
+const NETS: [&str; 11] = [
+    "#...\n####\n#...",
+    "#...\n####\n.#..",
+    "#...\n####\n..#.",
+    "#...\n####\n...#",
+    ".#..\n####\n.#..",
+    ".#..\n####\n..#.",
+    "##..\n.###\n.#..",
+    "##..\n.###\n..#.",
+    "##..\n.###\n...#",
+    "##..\n.##.\n..##",
+    "###..\n..###",
+];
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // the map runs up to the first empty line
+        let rows = input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            return Err(Scanner::new(input).error("a row of the map"));
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            let mut s = Scanner::at_line(row, i + 1);
+            s.take_while(|c| c == EMPTY || c == OPEN || c == WALL);
+            s.finish()?;
+        }
+
+        let line = input
+            .lines()
+            .nth(rows.len() + 1)
+            .ok_or_else(|| parse::missing(input, "the path below the map"))?;
+
+        let mut s = Scanner::at_line(line, rows.len() + 2);
+        let path = parse_path(&mut s)?;
+        s.finish()?;
+
+        // rows end where the map does, so fill them up to the widest one
+        let width = rows.iter().map(|row| row.len()).max().unwrap();
+        let map = Grid::from_fn(width, rows.len(), |(i, j)| {
+            rows[i].as_bytes().get(j).map_or(EMPTY, |&b| b as char)
+        });
+
+        // both parts need the map to fold up, and start on its first tile
+        let below =
+            |expected: &str, found: &str| ParseError::new(rows.len() + 1, 1, expected, found);
+        match faces(&map) {
+            None => return Err(below("a map of six square faces", "tiles in another shape")),
+            Some(faces) if !folds_into_cube(&faces) => {
+                return Err(below("faces that fold into a cube", "faces that overlap"))
+            }
+            Some(_) => {}
+        }
+        let start = map.position(|&c| c != EMPTY).unwrap();
+        if map[start] != OPEN {
+            return Err(ParseError::new(
+                1,
+                start.1 + 1,
+                "an open tile to start on",
+                "`#`",
+            ));
+        }
+
+        Ok(Notes { map, path })
+    }
+
+    fn part1(notes: &Self::Input) -> String {
+        part1::password(&notes.map, &notes.path).to_string()
+    }
+
+    fn part2(notes: &Self::Input) -> Option<String> {
+        Some(cube_password(&notes.map, &notes.path).to_string())
+    }
+
+    // one of the ways to unfold a cube, turned and flipped at random, with
+    // faces of size by size tiles and a path of 2 * size steps
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let n = size.max(2);
+
+        let net = rng.pick(&NETS);
+        let mut net = Grid::parse(net, "a face", |c| Some(c == '#')).unwrap();
+        for _ in 0..rng.index(4) {
+            net = turn(&net);
+        }
+        if rng.chance(0.5) {
+            net = flip(&net);
+        }
+
+        // both parts start at the top left of the first face
+        let start = net.position(|&face| face).unwrap();
+        let start = (start.0 * n, start.1 * n);
+        let map = Grid::from_fn(net.width() * n, net.height() * n, |(i, j)| {
+            match net[(i / n, j / n)] {
+                false => EMPTY,
+                true if (i, j) != start && rng.chance(0.1) => WALL,
+                true => OPEN,
+            }
+        });
+
+        let mut res = String::new();
+        for row in map.rows() {
+            let row = row.iter().collect::<String>();
+            res.push_str(row.trim_end());
+            res.push('\n');
+        }
+
+        res.push('\n');
+        let most = (2 * n).min(MAX_FORWARD) as i64;
+        res.push_str(&rng.range(1, most).to_string());
+        for _ in 1..2 * n {
+            res.push(*rng.pick(&['L', 'R']));
+            res.push_str(&rng.range(1, most).to_string());
+        }
+        res.push('\n');
+
+        res
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_common::grid::Grid;
+use aoc_common::point::Dir4;
+use day22::{Cube, Face};
+
+fn face(c: char) -> Face {
+    Grid::new(2, 2, c)
+}
+
+#[test]
+fn rolling_back_restores_the_front() {
+    let mut cube = Cube::new();
+    cube.set_front(face('a'));
+    for dir in Dir4::ALL {
+        cube.permute(dir);
+        cube.set_front(face('b'));
+        cube.permute_back(dir);
+        assert_eq!(cube.get_front(), Some(&face('a')));
+    }
+}
+
+#[test]
+fn four_rolls_go_all_the_way_round() {
+    let mut cube = Cube::new();
+    cube.set_front(face('a'));
+    cube.permute(Dir4::Right);
+    assert_eq!(cube.get_front(), None);
+
+    for _ in 0..3 {
+        cube.permute(Dir4::Right);
+    }
+    assert_eq!(cube.get_front(), Some(&face('a')));
+}
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day22::Day22;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day22::parse(EXAMPLE).unwrap();
+    assert_eq!(Day22::part1(&input), "6032");
+}
+
+#[test]
+fn part2() {
+    let input = Day22::parse(EXAMPLE).unwrap();
+    assert_eq!(Day22::part2(&input).unwrap(), "5031");
+}
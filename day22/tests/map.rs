@@ -0,0 +1,47 @@
+use aoc_common::Solution;
+use day22::Day22;
+
+fn parse_error(map: &str) -> (usize, usize, String) {
+    let err = Day22::parse(&format!("{}\n\n1R1\n", map)).unwrap_err();
+    (err.line, err.column, err.expected)
+}
+
+#[test]
+fn maps_fold_into_a_cube() {
+    // a cross of faces of one tile
+    assert!(Day22::parse(" .\n...\n .\n .\n\n1R1\n").is_ok());
+    // and of two
+    let cross = "  ..\n  ..\n......\n......\n  ..\n  ..\n  ..\n  ..";
+    assert!(Day22::parse(&format!("{}\n\n1R1\n", cross)).is_ok());
+}
+
+#[test]
+fn maps_of_other_shapes_are_errors() {
+    let faces = "a map of six square faces".to_string();
+    assert_eq!(parse_error("..."), (2, 1, faces.clone()));
+    assert_eq!(parse_error("....\n...."), (3, 1, faces.clone()));
+    // the right number of tiles, but not in whole faces
+    assert_eq!(parse_error(" ...\n..\n.\n.."), (5, 1, faces));
+}
+
+#[test]
+fn faces_that_overlap_are_errors() {
+    let overlap = "faces that fold into a cube".to_string();
+    assert_eq!(parse_error("...\n..."), (3, 1, overlap.clone()));
+    assert_eq!(parse_error("..\n..\n.."), (4, 1, overlap.clone()));
+    assert_eq!(parse_error("......"), (2, 1, overlap));
+}
+
+#[test]
+fn maps_start_on_an_open_tile() {
+    assert_eq!(
+        parse_error(" #\n...\n .\n ."),
+        (1, 2, "an open tile to start on".to_string())
+    );
+}
+
+#[test]
+fn steps_too_long_to_walk() {
+    let err = Day22::parse(" .\n...\n .\n .\n\n1R10001\n").unwrap_err();
+    assert_eq!((err.line, err.column), (6, 3));
+}
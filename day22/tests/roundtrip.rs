@@ -0,0 +1,52 @@
+use aoc_common::grid::Grid;
+use aoc_common::Solution;
+use day22::{Day22, Step};
+use proptest::prelude::*;
+
+fn path() -> impl Strategy<Value = Vec<Step>> {
+    let turn = prop_oneof![Just(Step::Left), Just(Step::Right)];
+    (
+        1..1000usize,
+        prop::collection::vec((turn, 1..1000usize), 0..8),
+    )
+        .prop_map(|(first, rest)| {
+            let mut path = vec![Step::Forward(first)];
+            for (turn, count) in rest {
+                path.push(turn);
+                path.push(Step::Forward(count));
+            }
+            path
+        })
+}
+
+fn show(step: &Step) -> String {
+    match step {
+        Step::Forward(count) => count.to_string(),
+        Step::Right => "R".to_string(),
+        Step::Left => "L".to_string(),
+    }
+}
+
+proptest! {
+    #[test]
+    fn map_and_path_parse_back(path in path()) {
+        // a cross of faces of one tile, its rows ending where the map does
+        let input = format!(
+            " .\n..#\n .\n #\n\n{}\n",
+            path.iter().map(show).collect::<String>()
+        );
+
+        // filled up with blanks to the widest row
+        let map = [
+            [' ', '.', ' '],
+            ['.', '.', '#'],
+            [' ', '.', ' '],
+            [' ', '#', ' '],
+        ];
+        let map = Grid::from_fn(3, 4, |(i, j)| map[i][j]);
+
+        let notes = Day22::parse(&input).unwrap();
+        prop_assert_eq!(notes.map, map);
+        prop_assert_eq!(notes.path, path);
+    }
+}
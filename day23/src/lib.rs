@@ -0,0 +1,216 @@
+use aoc_common::grid::{Grid, Point};
+use aoc_common::point::{Dir4, Dir8};
+use aoc_common::random::Rng;
+use aoc_common::render::{Frame, Recorder, Rgb};
+use aoc_common::step::Simulation;
+use aoc_common::{ParseError, Solution};
+use itertools::Itertools;
+
+const EMPTY: char = '.';
+const ELF: char = '#';
+
+const ROUNDS: usize = 10;
+
+type Map = Grid<char>;
+
+#[derive(Debug)]
+struct Move {
+    from: Point,
+    to: Point,
+}
+
+impl Move {
+    fn new(from: Point, to: Point) -> Self {
+        Self { from, to }
+    }
+}
+
+// north, south, west and east, in the order the elves first consider them
+const PROPOSALS: [Dir4; 4] = [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right];
+
+fn calculate_moves(map: &Map, round: usize) -> Vec<Move> {
+    let mut res = Vec::new();
+    for (pos, &tile) in map.iter() {
+        if tile == ELF && map.neighbors8(pos).any(|p| map[p] == ELF) {
+            for idx in (0..PROPOSALS.len()).map(|i| (i + round) % PROPOSALS.len()) {
+                // the way to go and the two diagonals next to it
+                let dir = Dir8::from(PROPOSALS[idx]);
+                let empty = [dir.turn_left(), dir, dir.turn_right()]
+                    .iter()
+                    .all(|d| map.step(pos, d.delta()).is_none_or(|p| map[p] == EMPTY));
+
+                if empty {
+                    if let Some(new_pos) = map.step(pos, dir.delta()) {
+                        res.push(Move::new(pos, new_pos));
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    res
+}
+
+fn apply_moves(map: &mut Map, moves: &mut [Move]) {
+    moves.sort_by_key(|m| m.to);
+    for (_, mut group) in &moves.iter().group_by(|m| m.to) {
+        let m = group.next().unwrap();
+        if group.next().is_none() {
+            map[m.from] = EMPTY;
+            map[m.to] = ELF;
+        }
+    }
+}
+
+fn bounds(map: &Map) -> (usize, usize, usize, usize) {
+    let (n, m) = (map.height(), map.width());
+
+    let mut res = (n / 2, n / 2, m / 2, m / 2);
+    for ((i, j), &tile) in map.iter() {
+        if tile == ELF {
+            res = (res.0.min(i), res.1.max(i), res.2.min(j), res.3.max(j));
+        }
+    }
+
+    res
+}
+
+fn empty_tiles(map: &Map) -> usize {
+    let b = bounds(map);
+    let count = map.cells().filter(|&&t| t == ELF).count();
+    (b.1 - b.0 + 1) * (b.3 - b.2 + 1) - count
+}
+
+fn frame(map: &Map) -> Frame {
+    map.map(|&tile| match tile {
+        ELF => Rgb(60, 200, 90),
+        _ => Rgb(20, 20, 30),
+    })
+}
+
+// the elves spreading out a round at a time, for 10 rounds in part 1 and
+// until none of them moves in part 2
+struct Grove {
+    map: Map,
+    round: usize,
+    rounds: Option<usize>,
+    moved: usize,
+    settled: bool,
+}
+
+impl Simulation for Grove {
+    fn step(&mut self) -> bool {
+        if self.settled || Some(self.round) == self.rounds {
+            return false;
+        }
+
+        let mut moves = calculate_moves(&self.map, self.round);
+        self.settled = moves.is_empty();
+        apply_moves(&mut self.map, &mut moves);
+        self.moved = moves.len();
+        self.round += 1;
+        true
+    }
+
+    // moved counts the elves that wanted to, some of which may have bumped into each other
+    fn counters(&self) -> Vec<(String, i64)> {
+        vec![
+            ("round".to_string(), self.round as i64),
+            ("moved".to_string(), self.moved as i64),
+            ("empty".to_string(), empty_tiles(&self.map) as i64),
+        ]
+    }
+
+    // the smallest rectangle with all the elves
+    fn render(&self) -> String {
+        let (top, bottom, left, right) = bounds(&self.map);
+        let mut res = String::new();
+        for row in &self.map.rows().collect::<Vec<_>>()[top..=bottom] {
+            res.extend(&row[left..=right]);
+            res.push('\n');
+        }
+        res
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(input, "a tile", |c| (c == EMPTY || c == ELF).then_some(c))?;
+
+        // room for the elves to spread out
+        let pad = map.width();
+        let shift = -(pad as isize);
+
+        Ok(Grid::from_fn(
+            map.width() + pad * 2,
+            map.height() + pad * 2,
+            |p| map.offset(p, (shift, shift)).map_or(EMPTY, |p| map[p]),
+        ))
+    }
+
+    fn part1(map: &Self::Input) -> String {
+        let mut map = map.clone();
+        for round in 0..ROUNDS {
+            let mut moves = calculate_moves(&map, round);
+            apply_moves(&mut map, &mut moves);
+        }
+
+        empty_tiles(&map).to_string()
+    }
+
+    fn part2(map: &Self::Input) -> Option<String> {
+        let mut map = map.clone();
+        for round in 0.. {
+            let mut moves = calculate_moves(&map, round);
+            if moves.is_empty() {
+                return Some((round + 1).to_string());
+            }
+
+            apply_moves(&mut map, &mut moves);
+        }
+
+        unreachable!()
+    }
+
+    fn simulate(map: &Self::Input, part: u32) -> Option<Box<dyn Simulation + '_>> {
+        Some(Box::new(Grove {
+            map: map.clone(),
+            round: 0,
+            rounds: (part == 1).then_some(ROUNDS),
+            moved: 0,
+            settled: false,
+        }))
+    }
+
+    // the elves spreading out until none of them moves, a frame per round
+    fn visualize(map: &Self::Input, rec: &mut Recorder) -> bool {
+        let mut map = map.clone();
+
+        rec.record(|| frame(&map));
+        for round in 0.. {
+            let mut moves = calculate_moves(&map, round);
+            if moves.is_empty() {
+                break;
+            }
+
+            apply_moves(&mut map, &mut moves);
+            rec.record(|| frame(&map));
+        }
+
+        true
+    }
+
+    // a size by size patch of ground, about half of it elves
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let map = Grid::from_fn(size, size, |_| if rng.chance(0.5) { ELF } else { EMPTY });
+        map.to_string()
+    }
+}
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day23::Day23;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day23::parse(EXAMPLE).unwrap();
+    assert_eq!(Day23::part1(&input), "110");
+}
+
+#[test]
+fn part2() {
+    let input = Day23::parse(EXAMPLE).unwrap();
+    assert_eq!(Day23::part2(&input).unwrap(), "20");
+}
@@ -0,0 +1,278 @@
+use aoc_common::grid::{Grid, Point};
+use aoc_common::parse;
+use aoc_common::random::Rng;
+use aoc_common::render::{Frame, Recorder, Rgb};
+use aoc_common::search;
+use aoc_common::step::Simulation;
+use aoc_common::{ParseError, Solution};
+use std::collections::HashSet;
+use std::iter;
+
+const WALL: char = '#';
+
+const UP: char = '^';
+const DOWN: char = 'v';
+const RIGHT: char = '>';
+const LEFT: char = '<';
+
+type Map = Grid<char>;
+
+// whether (i, j) is clear at minute iter, by looking where the blizzards that
+// could be there by then started from. the walls are only ever next to the
+// start and end, so any other point is inside the valley
+fn valid(map: &Map, (i, j): Point, iter: usize) -> bool {
+    if map[(i, j)] == WALL {
+        return false;
+    }
+
+    let n = map.height() - 2;
+    let m = map.width() - 2;
+
+    let (di, dj) = (iter % n, iter % m);
+
+    if map[(1 + (i - 1 + di) % n, j)] == UP {
+        return false;
+    }
+
+    if map[(1 + (i - 1 + n - di) % n, j)] == DOWN {
+        return false;
+    }
+
+    if map[(i, 1 + (j - 1 + dj) % m)] == LEFT {
+        return false;
+    }
+
+    if map[(i, 1 + (j - 1 + m - dj) % m)] == RIGHT {
+        return false;
+    }
+
+    true
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// the minute the expedition can first be at end, leaving start after minute
+// min - 1, or None if the blizzards never let it through. the blizzards repeat
+// every period minutes, so (point, minute % period) is all there is to a state
+fn solve(map: &Map, start: Point, end: Point, min: usize) -> Option<usize> {
+    let (n, m) = (map.height() - 2, map.width() - 2);
+    let period = n / gcd(n, m) * m;
+
+    let path = search::bfs(
+        [(start, (min - 1) % period)],
+        |&(pos, iter)| {
+            let iter = (iter + 1) % period;
+            iter::once(pos)
+                .chain(map.neighbors4(pos))
+                .filter(move |&next| next == start || next == end || valid(map, next, iter))
+                .map(move |next| (next, iter))
+        },
+        |&(pos, _)| pos == end,
+    )?;
+
+    Some(min - 1 + path.cost)
+}
+
+fn show(minute: Option<usize>) -> String {
+    minute.map_or_else(|| "unreachable".to_string(), |n| n.to_string())
+}
+
+pub struct Valley {
+    map: Map,
+    start: Point,
+    end: Point,
+}
+
+// everywhere the expedition could be at minute iter, from where it could be a
+// minute before, on the way from one end of the valley to the other
+fn spread(
+    map: &Map,
+    reach: &HashSet<Point>,
+    (from, to): (Point, Point),
+    iter: usize,
+) -> HashSet<Point> {
+    reach
+        .iter()
+        .flat_map(|&pos| iter::once(pos).chain(map.neighbors4(pos)))
+        .filter(|&next| next == from || next == to || valid(map, next, iter))
+        .collect()
+}
+
+// the trips of a part a minute at a time, following every way the expedition could go
+struct Trips<'a> {
+    valley: &'a Valley,
+    legs: Vec<(Point, Point)>,
+    leg: usize,
+    minute: usize,
+    reach: HashSet<Point>,
+}
+
+impl Simulation for Trips<'_> {
+    fn step(&mut self) -> bool {
+        let Some(&(from, to)) = self.legs.get(self.leg) else {
+            return false;
+        };
+
+        self.minute += 1;
+        self.reach = spread(&self.valley.map, &self.reach, (from, to), self.minute);
+        if self.reach.contains(&to) {
+            self.leg += 1;
+            self.reach = HashSet::from([to]);
+        }
+        true
+    }
+
+    fn counters(&self) -> Vec<(String, i64)> {
+        vec![
+            ("minute".to_string(), self.minute as i64),
+            ("leg".to_string(), self.leg as i64 + 1),
+            ("reach".to_string(), self.reach.len() as i64),
+        ]
+    }
+
+    // E wherever the expedition could be and * wherever there are blizzards
+    fn render(&self) -> String {
+        let Valley { map, start, end } = self.valley;
+        let tiles = Grid::from_fn(map.width(), map.height(), |p| {
+            if self.reach.contains(&p) {
+                'E'
+            } else if p == *start || p == *end {
+                '.'
+            } else if map[p] == WALL {
+                WALL
+            } else if !valid(map, p, self.minute) {
+                '*'
+            } else {
+                '.'
+            }
+        });
+        tiles.to_string()
+    }
+}
+
+// the valley at minute iter, with every point the expedition could be at by then
+fn frame(valley: &Valley, iter: usize, reach: &HashSet<Point>) -> Frame {
+    let Valley { map, start, end } = valley;
+    Grid::from_fn(map.width(), map.height(), |p| {
+        if reach.contains(&p) {
+            Rgb(60, 200, 90)
+        } else if p == *start || p == *end {
+            Rgb(20, 20, 30)
+        } else if map[p] == WALL {
+            Rgb(110, 100, 90)
+        } else if !valid(map, p, iter) {
+            Rgb(150, 190, 230)
+        } else {
+            Rgb(20, 20, 30)
+        }
+    })
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Input = Valley;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut map = Grid::parse(input, "a tile", |c| {
+            [WALL, '.', UP, DOWN, RIGHT, LEFT].contains(&c).then_some(c)
+        })?;
+        if map.width() < 3 || map.height() < 3 {
+            return Err(parse::missing(input, "a valley of at least 3 by 3 tiles"));
+        }
+
+        let start = (0, 1);
+        let end = (map.height() - 1, map.width() - 2);
+
+        map[start] = WALL;
+        map[end] = WALL;
+
+        Ok(Valley { map, start, end })
+    }
+
+    fn part1(valley: &Self::Input) -> String {
+        show(solve(&valley.map, valley.start, valley.end, 1))
+    }
+
+    fn part2(valley: &Self::Input) -> Option<String> {
+        let Valley { map, start, end } = valley;
+
+        let there = solve(map, *start, *end, 1);
+        let back = there.and_then(|there| solve(map, *end, *start, there + 1));
+        Some(show(
+            back.and_then(|back| solve(map, *start, *end, back + 1)),
+        ))
+    }
+
+    fn simulate(valley: &Self::Input, part: u32) -> Option<Box<dyn Simulation + '_>> {
+        let (start, end) = (valley.start, valley.end);
+        let legs = match part {
+            1 => vec![(start, end)],
+            _ => vec![(start, end), (end, start), (start, end)],
+        };
+
+        Some(Box::new(Trips {
+            valley,
+            legs,
+            leg: 0,
+            minute: 0,
+            reach: HashSet::from([start]),
+        }))
+    }
+
+    // the three trips of part 2, a frame per minute. rather than the one path
+    // found, they show everywhere the expedition could have got to
+    fn visualize(valley: &Self::Input, rec: &mut Recorder) -> bool {
+        let Valley { map, start, end } = valley;
+
+        let mut minute = 0;
+        let mut reach = HashSet::from([*start]);
+        rec.record(|| frame(valley, minute, &reach));
+
+        for (from, to) in [(*start, *end), (*end, *start), (*start, *end)] {
+            // the blizzards could keep the goal out of reach forever
+            let Some(last) = solve(map, from, to, minute + 1) else {
+                return true;
+            };
+
+            reach = HashSet::from([from]);
+            while minute < last {
+                minute += 1;
+                reach = spread(map, &reach, (from, to), minute);
+                rec.record(|| frame(valley, minute, &reach));
+            }
+        }
+
+        true
+    }
+
+    // a valley size tiles wide and half that high, a quarter of it blizzards.
+    // as in the puzzle, none blow up or down the columns of the start and end
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let (width, height) = (size.max(3) + 2, (size / 2).max(1) + 2);
+
+        let map = Grid::from_fn(width, height, |(i, j)| {
+            if (i, j) == (0, 1) || (i, j) == (height - 1, width - 2) {
+                '.'
+            } else if i == 0 || j == 0 || i == height - 1 || j == width - 1 {
+                WALL
+            } else if !rng.chance(0.25) {
+                '.'
+            } else if j == 1 || j == width - 2 {
+                *rng.pick(&[LEFT, RIGHT])
+            } else {
+                *rng.pick(&[UP, DOWN, LEFT, RIGHT])
+            }
+        });
+
+        map.to_string()
+    }
+}
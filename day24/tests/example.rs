@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day24::Day24;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day24::parse(EXAMPLE).unwrap();
+    assert_eq!(Day24::part1(&input), "18");
+}
+
+#[test]
+fn part2() {
+    let input = Day24::parse(EXAMPLE).unwrap();
+    assert_eq!(Day24::part2(&input).unwrap(), "54");
+}
+
+#[test]
+fn blocked_valley() {
+    let input = Day24::parse("#.###\n#.#.#\n###.#\n").unwrap();
+    assert_eq!(Day24::part1(&input), "unreachable");
+}
@@ -0,0 +1,116 @@
+use aoc_common::parse;
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+use std::iter;
+
+fn decimal(c: char) -> Option<i32> {
+    match c {
+        '0' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        '-' => Some(-1),
+        '=' => Some(-2),
+        _ => None,
+    }
+}
+
+fn snafu(i: i32) -> (char, i32) {
+    match i {
+        -5 => ('0', -1),
+        -4 => ('1', -1),
+        -3 => ('2', -1),
+        -2 => ('=', 0),
+        -1 => ('-', 0),
+        0 => ('0', 0),
+        1 => ('1', 0),
+        2 => ('2', 0),
+        3 => ('=', 1),
+        4 => ('-', 1),
+        5 => ('0', 1),
+        _ => unreachable!(),
+    }
+}
+
+// the sum of two SNAFU numbers, added digit by digit without going through
+// decimal, so there is no limit to how long they get. None if either of them
+// has a digit that is not a SNAFU one
+pub fn add_snafu(num1: &str, num2: &str) -> Option<String> {
+    let mut res = Vec::new();
+
+    let mut iter1 = num1.chars().rev();
+    let mut iter2 = num2.chars().rev();
+
+    let mut remainder = 0;
+    let mut ch;
+
+    loop {
+        let next1 = iter1.next();
+        let next2 = iter2.next();
+
+        if next1.is_none() && next2.is_none() {
+            break;
+        }
+
+        let i1 = next1.map_or(Some(0), decimal)?;
+        let i2 = next2.map_or(Some(0), decimal)?;
+        (ch, remainder) = snafu(i1 + i2 + remainder);
+        res.push(ch);
+    }
+
+    // carrying either way adds a digit
+    if remainder != 0 {
+        res.push(snafu(remainder).0);
+    }
+
+    // the top digits can cancel out, as in 1= plus -
+    let res = res.into_iter().rev().collect::<String>();
+    match res.trim_start_matches('0') {
+        "" => Some("0".to_string()),
+        trimmed => Some(trimmed.to_string()),
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    const PARTS: u32 = 1;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let numbers = parse::lines(input, |s| {
+            let number = s.take_while(|c| "=-012".contains(c));
+            if number.is_empty() {
+                return Err(s.error("a SNAFU number"));
+            }
+            Ok(number.to_string())
+        })?;
+        if numbers.is_empty() {
+            return Err(parse::missing(input, "a SNAFU number"));
+        }
+
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Input) -> String {
+        // parse only lets SNAFU digits through
+        numbers
+            .iter()
+            .try_fold("0".to_string(), |acc, n| add_snafu(&acc, n))
+            .unwrap()
+    }
+
+    // size SNAFU numbers of up to 20 digits
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let digits = rng.range(0, 19);
+                iter::once(*rng.pick(&['1', '2']))
+                    .chain((0..digits).map(|_| *rng.pick(&['=', '-', '0', '1', '2'])))
+                    .chain(iter::once('\n'))
+                    .collect::<String>()
+            })
+            .collect()
+    }
+}
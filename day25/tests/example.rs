@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day25::Day25;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day25::parse(EXAMPLE).unwrap();
+    assert_eq!(Day25::part1(&input), "2=-1=0");
+}
+
+#[test]
+fn no_part2() {
+    let input = Day25::parse(EXAMPLE).unwrap();
+    assert_eq!(Day25::part2(&input), None);
+}
+
+#[test]
+fn negative_sums() {
+    let input = Day25::parse("-\n=\n").unwrap();
+    assert_eq!(Day25::part1(&input), "-2");
+}
@@ -0,0 +1,27 @@
+use day25::add_snafu;
+
+fn add(num1: &str, num2: &str) -> String {
+    add_snafu(num1, num2).unwrap()
+}
+
+#[test]
+fn sums_carry_both_ways() {
+    assert_eq!(add("1", "1"), "2");
+    assert_eq!(add("2", "1"), "1=");
+    assert_eq!(add("2=", "2="), "1=1");
+    assert_eq!(add("1=-0-2", "12111"), "1-111=");
+    assert_eq!(add("-", "="), "-2");
+    assert_eq!(add("=", "="), "-1");
+}
+
+#[test]
+fn sums_drop_leading_zeros() {
+    assert_eq!(add("1=", "-"), "2");
+    assert_eq!(add("1", "-"), "0");
+}
+
+#[test]
+fn other_digits_are_not_added() {
+    assert_eq!(add_snafu("1", "3"), None);
+    assert_eq!(add_snafu("x", "1"), None);
+}
@@ -0,0 +1,191 @@
+use aoc_common::bitset::BitSet;
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+use std::num::NonZeroUsize;
+
+// a set of items as a bit per priority, a u64 as there are 52 of them
+pub type Items = BitSet;
+
+// the size of the groups of the puzzle
+pub const GROUP: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+pub fn priority(item: char) -> Option<usize> {
+    match item {
+        'a'..='z' => Some(item as usize - 'a' as usize + 1),
+        'A'..='Z' => Some(item as usize - 'A' as usize + 27),
+        _ => None,
+    }
+}
+
+// the items of the string, or None if it has anything but letters
+pub fn items(s: &str) -> Option<Items> {
+    s.chars()
+        .map(priority)
+        .collect::<Option<Vec<_>>>()
+        .map(Items::from_iter)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    pub left: Items,
+    pub right: Items,
+}
+
+impl Rucksack {
+    pub fn all(&self) -> Items {
+        self.left | self.right
+    }
+
+    // the items wrongly packed in both compartments
+    pub fn misplaced(&self) -> Items {
+        self.left & self.right
+    }
+}
+
+// what a set of items found instead of the single one it should have
+fn how_many(items: Items) -> String {
+    match items.len() {
+        0 => String::from("none"),
+        n => format!("{} of them", n),
+    }
+}
+
+// the items every rucksack of the group has
+pub fn common_items(group: &[Rucksack]) -> Items {
+    group
+        .iter()
+        .map(Rucksack::all)
+        .reduce(|set, next| set & next)
+        .unwrap_or_default()
+}
+
+// the priority of the badge of every group of size rucksacks in a row, the one
+// item its rucksacks all have. errors point at the first rucksack of a group
+pub fn badges(rucks: &[Rucksack], size: NonZeroUsize) -> Result<Vec<usize>, ParseError> {
+    let size = size.get();
+    if !rucks.len().is_multiple_of(size) {
+        let expected = format!("{} more rucksacks", size - rucks.len() % size);
+        return Err(ParseError::new(
+            rucks.len() + 1,
+            1,
+            expected,
+            "end of input",
+        ));
+    }
+
+    rucks
+        .chunks(size)
+        .enumerate()
+        .map(|(k, group)| {
+            let common = common_items(group);
+            if common.len() != 1 {
+                let expected = format!("a single item common to the group of {}", size);
+                return Err(ParseError::new(k * size + 1, 1, expected, how_many(common)));
+            }
+            Ok(common.first().unwrap())
+        })
+        .collect()
+}
+
+// three rucksacks, whose only item in common is the badge. every rucksack gets
+// its own share of the other letters, so nothing else can be in all three
+fn generate_group(rng: &mut Rng) -> String {
+    let mut letters = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+    rng.shuffle(&mut letters);
+    let (badge, rest) = (letters[0], &letters[1..]);
+
+    let mut res = String::new();
+    for share in rest.chunks(rest.len() / 3) {
+        // the item in both compartments, and the ones only ever in one of them
+        let mut items = share.to_vec();
+        items.push(badge);
+        let both = items.swap_remove(rng.index(items.len()));
+        let (mut left, mut right) = (vec![both], vec![both]);
+        for item in items {
+            if rng.chance(0.5) {
+                left.push(item);
+            } else {
+                right.push(item);
+            }
+        }
+
+        let fewest = left.len().max(right.len());
+        let half = fewest + rng.index(8);
+        for side in [left, right] {
+            let mut half_items = side.clone();
+            half_items.extend((side.len()..half).map(|_| *rng.pick(&side)));
+            rng.shuffle(&mut half_items);
+            res.extend(half_items);
+        }
+        res.push('\n');
+    }
+
+    res
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Input = Vec<Rucksack>;
+
+    // every rucksack has a single misplaced item, and every group a badge
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rucks = parse::lines(input, |s: &mut Scanner| {
+            let start = s.clone();
+            let items = s.take_while(|c| c.is_ascii_alphabetic());
+            if items.is_empty() {
+                return Err(s.error("an item letter"));
+            }
+            if items.len() % 2 == 1 {
+                return Err(s.error("one more item, both compartments hold as many"));
+            }
+
+            // only letters were taken, so both halves are items
+            let (left, right) = items.split_at(items.len() / 2);
+            let ruck = Rucksack {
+                left: self::items(left).unwrap(),
+                right: self::items(right).unwrap(),
+            };
+            if ruck.misplaced().len() != 1 {
+                let (line, column) = start.position();
+                let expected = "a single item in both compartments";
+                return Err(ParseError::new(
+                    line,
+                    column,
+                    expected,
+                    how_many(ruck.misplaced()),
+                ));
+            }
+            Ok(ruck)
+        })?;
+
+        badges(&rucks, GROUP)?;
+        Ok(rucks)
+    }
+
+    fn part1(rucks: &Self::Input) -> String {
+        rucks
+            .iter()
+            .map(|ruck| ruck.misplaced().first().unwrap())
+            .sum::<usize>()
+            .to_string()
+    }
+
+    fn part2(rucks: &Self::Input) -> Option<String> {
+        Some(
+            badges(rucks, GROUP)
+                .unwrap()
+                .into_iter()
+                .sum::<usize>()
+                .to_string(),
+        )
+    }
+
+    // size groups of three rucksacks
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size).map(|_| generate_group(rng)).collect()
+    }
+}
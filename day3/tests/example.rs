@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day3::Day3;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day3::parse(EXAMPLE).unwrap();
+    assert_eq!(Day3::part1(&input), "157");
+}
+
+#[test]
+fn part2() {
+    let input = Day3::parse(EXAMPLE).unwrap();
+    assert_eq!(Day3::part2(&input).unwrap(), "70");
+}
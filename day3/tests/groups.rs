@@ -0,0 +1,74 @@
+use aoc_common::Solution;
+use day3::{badges, common_items, items, Day3, Rucksack};
+use std::num::NonZeroUsize;
+
+const EXAMPLE: &str = include_str!("../example");
+
+fn group(size: usize) -> NonZeroUsize {
+    NonZeroUsize::new(size).unwrap()
+}
+
+#[test]
+fn items_are_bits_by_priority() {
+    assert_eq!(items("aZ").unwrap().bits(), 1 << 1 | 1 << 52);
+    assert_eq!(items("bba").unwrap().iter().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(items("a1"), None);
+    assert_eq!(items("é"), None);
+}
+
+#[test]
+fn all_common_items_of_a_group() {
+    let rucks = Day3::parse(EXAMPLE).unwrap();
+    assert_eq!(common_items(&rucks[..3]), items("r").unwrap());
+    // the first two rucksacks share more than their badge
+    assert_eq!(common_items(&rucks[..2]), items("rsFMf").unwrap());
+    assert_eq!(common_items(&[]), items("").unwrap());
+}
+
+#[test]
+fn groups_of_any_size() {
+    let rucks = Day3::parse(EXAMPLE).unwrap();
+    assert_eq!(badges(&rucks, group(3)).unwrap(), [18, 52]);
+}
+
+#[test]
+fn groups_need_a_single_badge() {
+    let rucks = Day3::parse(EXAMPLE).unwrap();
+
+    let err = badges(&rucks, group(2)).unwrap_err();
+    assert_eq!((err.line, err.found.as_str()), (1, "5 of them"));
+
+    let err = badges(&rucks, group(6)).unwrap_err();
+    assert_eq!((err.line, err.found.as_str()), (1, "none"));
+
+    let err = badges(&rucks, group(4)).unwrap_err();
+    assert_eq!((err.line, err.expected.as_str()), (7, "2 more rucksacks"));
+}
+
+#[test]
+fn rucksacks_need_a_single_misplaced_item() {
+    let rucksack = Rucksack {
+        left: items("ab").unwrap(),
+        right: items("bc").unwrap(),
+    };
+    assert_eq!(rucksack.misplaced(), items("b").unwrap());
+    assert_eq!(rucksack.all(), items("abc").unwrap());
+
+    let err = Day3::parse("aba\nxx\nxx\n").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 4));
+
+    let err = Day3::parse("ab\nxx\nxx\n").unwrap_err();
+    assert_eq!((err.line, err.found.as_str()), (1, "none"));
+
+    let err = Day3::parse("abab\nbb\nbb\n").unwrap_err();
+    assert_eq!((err.line, err.found.as_str()), (1, "2 of them"));
+}
+
+#[test]
+fn groups_of_the_puzzle_need_a_badge() {
+    let err = Day3::parse("aa\nbb\ncc\n").unwrap_err();
+    assert_eq!((err.line, err.found.as_str()), (1, "none"));
+
+    let err = Day3::parse("aa\naa\n").unwrap_err();
+    assert_eq!(err.line, 3);
+}
@@ -0,0 +1,75 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+
+type Range = (i32, i32);
+
+fn range(s: &mut Scanner) -> parse::Result<Range> {
+    let lo = s.number()?;
+    s.expect("-")?;
+    let hi = s.number()?;
+    Ok((lo, hi))
+}
+
+fn between(x: i32, a: i32, b: i32) -> bool {
+    x >= a && x <= b
+}
+
+fn overlap(lo1: i32, hi1: i32, lo2: i32, hi2: i32) -> bool {
+    between(lo1, lo2, hi2)
+        || between(hi1, lo2, hi2)
+        || between(lo2, lo1, hi1)
+        || between(hi2, lo1, hi1)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, |s| {
+            let r1 = range(s)?;
+            s.expect(",")?;
+            let r2 = range(s)?;
+            Ok((r1, r2))
+        })
+    }
+
+    fn part1(pairs: &Self::Input) -> String {
+        pairs
+            .iter()
+            .filter(|((lo1, hi1), (lo2, hi2))| {
+                (lo1 <= lo2 && hi1 >= hi2) || (lo2 <= lo1 && hi2 >= hi1)
+            })
+            .count()
+            .to_string()
+    }
+
+    fn part2(pairs: &Self::Input) -> Option<String> {
+        Some(
+            pairs
+                .iter()
+                .filter(|&&((lo1, hi1), (lo2, hi2))| overlap(lo1, hi1, lo2, hi2))
+                .count()
+                .to_string(),
+        )
+    }
+
+    // size pairs of elves
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut range = || {
+            let lo = rng.range(1, 99);
+            (lo, rng.range(lo, 99))
+        };
+
+        (0..size)
+            .map(|_| {
+                let ((lo1, hi1), (lo2, hi2)) = (range(), range());
+                format!("{}-{},{}-{}\n", lo1, hi1, lo2, hi2)
+            })
+            .collect()
+    }
+}
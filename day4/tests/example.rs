@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day4::Day4;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day4::parse(EXAMPLE).unwrap();
+    assert_eq!(Day4::part1(&input), "2");
+}
+
+#[test]
+fn part2() {
+    let input = Day4::parse(EXAMPLE).unwrap();
+    assert_eq!(Day4::part2(&input).unwrap(), "4");
+}
@@ -0,0 +1,210 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Step {
+    pub count: i32,
+    pub from: usize,
+    pub to: usize,
+}
+
+// heights are those of the stacks before the step, which it then updates so
+// that no step ever moves more crates than its stack holds
+fn parse_step(s: &mut Scanner, heights: &mut [usize]) -> parse::Result<Step> {
+    let n = heights.len();
+    s.expect("move ")?;
+    let at_count = s.clone();
+    let count = s.number_in(0, i32::MAX)?;
+    s.expect(" from ")?;
+    let from = s.number_in(1, n)?;
+    s.expect(" to ")?;
+    let at_to = s.clone();
+    let to = s.number_in(1, n)?;
+
+    if to == from {
+        return Err(at_to.error(format!("a stack other than {}", from)));
+    }
+    let height = heights[from - 1];
+    if count as usize > height {
+        return Err(at_count.error(format!("at most the {} crates of stack {}", height, from)));
+    }
+    heights[from - 1] -= count as usize;
+    heights[to - 1] += count as usize;
+
+    Ok(Step { count, from, to })
+}
+
+// one row of the drawing, a crate is drawn as `[X]` and missing ones as blanks
+fn parse_crates(s: &mut Scanner, n: usize) -> parse::Result<Vec<Option<char>>> {
+    let mut row = Vec::new();
+
+    for i in 0..n {
+        if s.is_done() || (i > 0 && !s.eat(" ")) {
+            break;
+        }
+
+        if s.eat("   ") {
+            row.push(None);
+        } else {
+            s.expect("[")?;
+            match s.peek() {
+                Some(c) if c.is_ascii_alphabetic() => {
+                    s.bump();
+                    row.push(Some(c));
+                }
+                _ => return Err(s.error("a crate letter")),
+            }
+            s.expect("]")?;
+        }
+    }
+
+    Ok(row)
+}
+
+fn parse_labels(s: &mut Scanner) -> parse::Result<usize> {
+    let mut n = 0;
+
+    loop {
+        s.take_while(|c| c == ' ');
+        if s.is_done() {
+            break;
+        }
+
+        n += 1;
+        s.number_in(n, n)?;
+    }
+
+    Ok(n)
+}
+
+impl Step {
+    // parse made sure the from stack holds enough crates
+    fn execute(&self, stacks: &mut [Vec<char>]) -> &Self {
+        for _ in 0..self.count {
+            let c = stacks[self.from - 1].pop().unwrap();
+            stacks[self.to - 1].push(c);
+        }
+        self
+    }
+
+    fn execute_ordered(&self, stacks: &mut [Vec<char>]) -> &Self {
+        let from = &mut stacks[self.from - 1];
+        let moved = from.split_off(from.len() - self.count as usize);
+        stacks[self.to - 1].extend(moved);
+        self
+    }
+}
+
+fn find_empty_line(lines: &[&str]) -> Option<usize> {
+    lines.iter().position(|l| l.is_empty())
+}
+
+// the top crate of every stack that has one
+fn top_crates(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|s| s.last()).collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = (Vec<Vec<char>>, Vec<Step>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let scanner = |i: usize| Scanner::at_line(lines[i], i + 1);
+
+        let si = match find_empty_line(&lines) {
+            Some(si) if si > 0 => si,
+            _ => return Err(parse::missing(input, "a drawing followed by an empty line")),
+        };
+
+        let mut s = scanner(si - 1);
+        let n = parse_labels(&mut s)?;
+        let mut stacks = vec![Vec::new(); n];
+
+        for i in (0..si - 1).rev() {
+            let mut s = scanner(i);
+            for (stack, c) in parse_crates(&mut s, n)?.into_iter().enumerate() {
+                if let Some(c) = c {
+                    stacks[stack].push(c);
+                }
+            }
+            s.finish()?;
+        }
+
+        let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+        let steps = (si + 1..lines.len())
+            .map(|i| {
+                let mut s = scanner(i);
+                let step = parse_step(&mut s, &mut heights)?;
+                s.finish()?;
+                Ok(step)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((stacks, steps))
+    }
+
+    fn part1((stacks, steps): &Self::Input) -> String {
+        let mut stacks = stacks.clone();
+        for step in steps {
+            step.execute(&mut stacks);
+        }
+        top_crates(&stacks)
+    }
+
+    fn part2((stacks, steps): &Self::Input) -> Option<String> {
+        let mut stacks = stacks.clone();
+        for step in steps {
+            step.execute_ordered(&mut stacks);
+        }
+        Some(top_crates(&stacks))
+    }
+
+    // nine stacks and size steps, which never empty a stack
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut stacks = (0..9)
+            .map(|_| {
+                let height = rng.range(1, 8);
+                (0..height).map(|_| rng.char_in('A', 'Z')).collect()
+            })
+            .collect::<Vec<Vec<char>>>();
+
+        let height = stacks.iter().map(Vec::len).max().unwrap();
+        let mut res = String::new();
+        for level in (0..height).rev() {
+            let row = stacks
+                .iter()
+                .map(|stack| stack.get(level).map_or("   ".to_string(), |c| format!("[{}]", c)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            res.push_str(row.trim_end());
+            res.push('\n');
+        }
+        let labels = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect::<Vec<_>>();
+        res.push_str(labels.join(" ").trim_end());
+        res.push_str("\n\n");
+
+        // only the heights matter for which steps are valid, and both cranes agree on those
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.index(stacks.len());
+                if stacks[from].len() > 1 {
+                    break from;
+                }
+            };
+            let to = (from + rng.range(1, stacks.len() as i64 - 1) as usize) % stacks.len();
+            let count = rng.range(1, stacks[from].len() as i64 - 1) as usize;
+
+            let n = stacks[from].len() - count;
+            let moved = stacks[from].split_off(n);
+            stacks[to].extend(moved);
+            res.push_str(&format!("move {} from {} to {}\n", count, from + 1, to + 1));
+        }
+
+        res
+    }
+}
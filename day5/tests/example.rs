@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day5::Day5;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day5::parse(EXAMPLE).unwrap();
+    assert_eq!(Day5::part1(&input), "CMZ");
+}
+
+#[test]
+fn part2() {
+    let input = Day5::parse(EXAMPLE).unwrap();
+    assert_eq!(Day5::part2(&input).unwrap(), "MCD");
+}
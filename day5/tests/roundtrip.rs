@@ -0,0 +1,80 @@
+use aoc_common::Solution;
+use day5::{Day5, Step};
+use proptest::prelude::*;
+
+fn stacks() -> impl Strategy<Value = Vec<Vec<char>>> {
+    prop::collection::vec(
+        prop::collection::vec(prop::char::range('A', 'Z'), 0..6),
+        2..10,
+    )
+}
+
+// steps as (count, from, offset to the other stack), which only become valid
+// once fitted to the heights of the stacks they move between
+fn steps(n: usize) -> impl Strategy<Value = Vec<(i32, usize, usize)>> {
+    prop::collection::vec((0..100i32, 0..n, 1..n), 0..8)
+}
+
+fn fit(stacks: &[Vec<char>], steps: &[(i32, usize, usize)]) -> Vec<Step> {
+    let n = stacks.len();
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+    steps
+        .iter()
+        .map(|&(count, from, offset)| {
+            let to = (from + offset) % n;
+            let count = count % (heights[from] as i32 + 1);
+            heights[from] -= count as usize;
+            heights[to] += count as usize;
+            Step {
+                count,
+                from: from + 1,
+                to: to + 1,
+            }
+        })
+        .collect()
+}
+
+// the drawing the way the puzzle does it, without trailing blanks
+fn draw(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    let mut res = String::new();
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| {
+                stack
+                    .get(level)
+                    .map_or("   ".to_string(), |c| format!("[{}]", c))
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        res.push_str(row.trim_end());
+        res.push('\n');
+    }
+    let labels = (1..=stacks.len())
+        .map(|i| format!(" {} ", i))
+        .collect::<Vec<_>>();
+    res.push_str(labels.join(" ").trim_end());
+    res.push_str("\n\n");
+    res
+}
+
+proptest! {
+    #[test]
+    fn drawing_and_steps_parse_back(
+        (stacks, steps) in stacks().prop_flat_map(|stacks| {
+            let n = stacks.len();
+            (Just(stacks), steps(n))
+        }),
+    ) {
+        let steps = fit(&stacks, &steps);
+        let mut input = draw(&stacks);
+        for step in &steps {
+            input.push_str(&format!("move {} from {} to {}\n", step.count, step.from, step.to));
+        }
+
+        let (parsed, parsed_steps) = Day5::parse(&input).unwrap();
+        prop_assert_eq!(parsed, stacks);
+        prop_assert_eq!(parsed_steps, steps);
+    }
+}
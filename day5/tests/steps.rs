@@ -0,0 +1,29 @@
+use aoc_common::Solution;
+use day5::Day5;
+
+const DRAWING: &str = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+
+#[test]
+fn steps_move_between_different_stacks() {
+    let err = Day5::parse(&format!("{}move 1 from 1 to 1\n", DRAWING)).unwrap_err();
+    assert_eq!((err.line, err.column), (6, 18));
+}
+
+#[test]
+fn steps_move_at_most_the_crates_of_a_stack() {
+    assert!(Day5::parse(&format!("{}move 3 from 2 to 1\n", DRAWING)).is_ok());
+    let err = Day5::parse(&format!("{}move 4 from 2 to 1\n", DRAWING)).unwrap_err();
+    assert_eq!((err.line, err.column), (6, 6));
+
+    // heights carry over from the steps before
+    let input = format!("{}move 1 from 3 to 1\nmove 2 from 3 to 2\n", DRAWING);
+    let err = Day5::parse(&input).unwrap_err();
+    assert_eq!((err.line, err.column), (7, 6));
+}
+
+#[test]
+fn empty_stacks_have_no_top_crate() {
+    let input = Day5::parse(&format!("{}move 1 from 3 to 1\n", DRAWING)).unwrap();
+    assert_eq!(Day5::part1(&input), "PD");
+    assert_eq!(Day5::part2(&input).unwrap(), "PD");
+}
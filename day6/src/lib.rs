@@ -0,0 +1,75 @@
+use aoc_common::parse;
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+use std::iter;
+
+fn distinc_chars(s: &str) -> bool {
+    let mut seen = [false; 26];
+    for b in s.bytes().map(|b| (b - b'a') as usize) {
+        if seen[b] {
+            return false;
+        }
+
+        seen[b] = true;
+    }
+
+    true
+}
+
+fn first_distinct_seq(s: &str, w: usize) -> Option<usize> {
+    for i in 0..=s.len().checked_sub(w)? {
+        if distinc_chars(&s[i..i + w]) {
+            return Some(i + w);
+        }
+    }
+
+    None
+}
+
+fn show(marker: Option<usize>) -> String {
+    marker.map_or_else(|| "no marker".to_string(), |n| n.to_string())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input, |s| {
+            let stream = s.take_while(|c| c.is_ascii_lowercase());
+            Ok(stream.to_string())
+        })?;
+
+        match lines.len() {
+            1 => Ok(lines.remove(0)),
+            _ => Err(parse::missing(input, "a single line")),
+        }
+    }
+
+    fn part1(stream: &Self::Input) -> String {
+        show(first_distinct_seq(stream, 4))
+    }
+
+    fn part2(stream: &Self::Input) -> Option<String> {
+        Some(show(first_distinct_seq(stream, 14)))
+    }
+
+    // a stream of size chars from too few letters for a 14 char marker, but
+    // for one put in somewhere
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut letters = ('a'..='z').collect::<Vec<_>>();
+        rng.shuffle(&mut letters);
+        let (marker, common) = letters.split_at(14);
+
+        let mut stream = (0..size.saturating_sub(14))
+            .map(|_| *rng.pick(common))
+            .collect::<Vec<_>>();
+        let at = rng.index(stream.len() + 1);
+        stream.splice(at..at, marker.iter().copied());
+
+        stream.into_iter().chain(iter::once('\n')).collect()
+    }
+}
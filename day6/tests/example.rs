@@ -0,0 +1,26 @@
+use aoc_common::Solution;
+use day6::Day6;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day6::parse(EXAMPLE).unwrap();
+    assert_eq!(Day6::part1(&input), "7");
+}
+
+#[test]
+fn part2() {
+    let input = Day6::parse(EXAMPLE).unwrap();
+    assert_eq!(Day6::part2(&input).unwrap(), "19");
+}
+
+#[test]
+fn streams_without_a_marker() {
+    let input = Day6::parse("abc\n").unwrap();
+    assert_eq!(Day6::part1(&input), "no marker");
+
+    let input = Day6::parse("abcdabcdabcdabcd\n").unwrap();
+    assert_eq!(Day6::part1(&input), "4");
+    assert_eq!(Day6::part2(&input).unwrap(), "no marker");
+}
@@ -0,0 +1,242 @@
+use aoc_common::parse::{self, Scanner};
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+use std::collections::HashSet;
+
+// no file is bigger than the whole disk, so adding them all up can't overflow
+const DISK: u64 = 70_000_000;
+
+pub enum Entry {
+    Dir(Vec<(String, Entry)>, u64),
+    File(u64),
+}
+
+enum Line {
+    Cd(String),
+    Ls,
+    Entry(String, Entry),
+}
+
+fn parse_name(s: &mut Scanner) -> parse::Result<String> {
+    let name = s.take_while(|c| !c.is_whitespace());
+    if name.is_empty() {
+        Err(s.error("a name"))
+    } else {
+        Ok(name.to_string())
+    }
+}
+
+fn parse_line(s: &mut Scanner) -> parse::Result<Line> {
+    if s.eat("$ ") {
+        match s.choice(&["cd ", "ls"])? {
+            "ls" => Ok(Line::Ls),
+            _ => Ok(Line::Cd(parse_name(s)?)),
+        }
+    } else if s.eat("dir ") {
+        Ok(Line::Entry(parse_name(s)?, Entry::Dir(Vec::new(), 0)))
+    } else {
+        let size = s.number_in(0, DISK)?;
+        s.expect(" ")?;
+        Ok(Line::Entry(parse_name(s)?, Entry::File(size)))
+    }
+}
+
+// the tree as the puzzle draws it, a line per entry under the root
+pub fn tree(root: &Entry) -> String {
+    let mut res = String::new();
+    push_entry(&mut res, "/", root, 0);
+    res
+}
+
+fn push_entry(res: &mut String, name: &str, e: &Entry, indent: usize) {
+    let pad = " ".repeat(indent * 2);
+    match e {
+        Entry::Dir(v, size) => {
+            res.push_str(&format!("{}- {} (dir, size={})\n", pad, name, size));
+            v.iter().for_each(|(a, e)| push_entry(res, a, e, indent + 1));
+        }
+        Entry::File(size) => {
+            res.push_str(&format!("{}- {} (file, size={})\n", pad, name, size));
+        }
+    }
+}
+
+fn calculate_sizes(e: &mut Entry) -> u64 {
+    match e {
+        Entry::File(size) => *size,
+        Entry::Dir(v, size) => {
+            *size = v.iter_mut().map(|e| calculate_sizes(&mut e.1)).sum();
+            *size
+        }
+    }
+}
+
+fn sum_directories<const T: u64>(e: &Entry) -> u64 {
+    match e {
+        Entry::File(_) => 0,
+        Entry::Dir(v, size) => {
+            v.iter().map(|e| sum_directories::<T>(&e.1)).sum::<u64>()
+                + if *size < T { *size } else { 0 }
+        }
+    }
+}
+
+fn smallest_dir(e: &Entry, target: u64) -> Option<u64> {
+    match e {
+        Entry::File(_) => None,
+        Entry::Dir(v, size) => v
+            .iter()
+            .filter_map(|e| smallest_dir(&e.1, target))
+            .chain(Some(*size))
+            .filter(|&size| size >= target)
+            .min(),
+    }
+}
+
+fn find_dir<'a>(mut root: &'a mut Entry, pwd: &[String]) -> Option<&'a mut Vec<(String, Entry)>> {
+    for dir in pwd {
+        root = match root {
+            Entry::Dir(v, _) => &mut v.iter_mut().find(|e| e.0 == *dir)?.1,
+            Entry::File(_) => return None,
+        }
+    }
+
+    match root {
+        Entry::Dir(v, _) => Some(v),
+        Entry::File(_) => None,
+    }
+}
+
+fn build_fs(root: &mut Entry, pwd: &mut Vec<String>, s: &mut Scanner) -> parse::Result<()> {
+    let start = s.clone();
+
+    match parse_line(s)? {
+        Line::Cd(dir) if dir == "/" => pwd.clear(),
+        Line::Cd(dir) if dir == ".." => {
+            pwd.pop();
+        }
+        Line::Cd(dir) => {
+            pwd.push(dir);
+            if find_dir(root, pwd).is_none() {
+                let mut at = start;
+                at.expect("$ cd ")?;
+                return Err(at.error("a directory listed by ls"));
+            }
+        }
+        Line::Ls => {}
+        Line::Entry(name, entry) => {
+            // pwd only ever holds directories that were checked to exist
+            find_dir(root, pwd).unwrap().push((name, entry));
+        }
+    }
+
+    Ok(())
+}
+
+// lists dir and then walks into every directory in it
+fn generate_listing(
+    rng: &mut Rng,
+    dir: usize,
+    children: &[Vec<usize>],
+    files: &[Vec<i64>],
+    res: &mut String,
+) {
+    let names = generate_names(rng, children[dir].len() + files[dir].len());
+    let (dirs, file_names) = names.split_at(children[dir].len());
+
+    let mut entries = dirs
+        .iter()
+        .map(|name| format!("dir {}", name))
+        .chain(
+            file_names
+                .iter()
+                .zip(&files[dir])
+                .map(|(name, size)| format!("{} {}.txt", size, name)),
+        )
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut entries);
+
+    res.push_str("$ ls\n");
+    for entry in entries {
+        res.push_str(&entry);
+        res.push('\n');
+    }
+
+    for (name, &child) in dirs.iter().zip(&children[dir]) {
+        res.push_str(&format!("$ cd {}\n", name));
+        generate_listing(rng, child, children, files, res);
+        res.push_str("$ cd ..\n");
+    }
+}
+
+// n different lowercase names
+fn generate_names(rng: &mut Rng, n: usize) -> Vec<String> {
+    let mut names = HashSet::new();
+    while names.len() < n {
+        let len = rng.range(1, 8);
+        names.insert((0..len).map(|_| rng.char_in('a', 'z')).collect::<String>());
+    }
+
+    let mut names = names.into_iter().collect::<Vec<_>>();
+    names.sort();
+    rng.shuffle(&mut names);
+    names
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type Input = Entry;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut root = Entry::Dir(vec![], 0);
+        let mut pwd = Vec::new();
+
+        parse::lines(input, |s| build_fs(&mut root, &mut pwd, s))?;
+        calculate_sizes(&mut root);
+
+        Ok(root)
+    }
+
+    fn part1(root: &Self::Input) -> String {
+        sum_directories::<100000>(root).to_string()
+    }
+
+    fn part2(root: &Self::Input) -> Option<String> {
+        let to_free = if let Entry::Dir(_, tot) = root {
+            tot.saturating_sub(40000000)
+        } else {
+            unreachable!()
+        };
+
+        Some(smallest_dir(root, to_free).unwrap().to_string())
+    }
+
+    // a terminal session exploring size directories
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        // every directory after the root goes in one of the ones before it
+        let mut children = vec![Vec::new(); size];
+        for dir in 1..size {
+            children[rng.index(dir)].push(dir);
+        }
+
+        // a disk between two thirds and nine tenths full, so there is
+        // something to delete but it all still fits, with mostly small files
+        let mut files = (0..size)
+            .map(|_| (0..rng.range(0, 4)).map(|_| rng.range(1, 100).pow(3)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let total = files.iter().flatten().sum::<i64>().max(1);
+        let target = rng.range(47_000_000, 63_000_000);
+        for size in files.iter_mut().flatten() {
+            *size = (*size * target / total).max(1);
+        }
+
+        let mut res = String::from("$ cd /\n");
+        generate_listing(rng, 0, &children, &files, &mut res);
+        res
+    }
+}
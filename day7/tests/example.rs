@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day7::Day7;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day7::parse(EXAMPLE).unwrap();
+    assert_eq!(Day7::part1(&input), "95437");
+}
+
+#[test]
+fn part2() {
+    let input = Day7::parse(EXAMPLE).unwrap();
+    assert_eq!(Day7::part2(&input).unwrap(), "24933642");
+}
+
+#[test]
+fn files_bigger_than_the_disk() {
+    let err = Day7::parse("$ cd /\n$ ls\n70000001 a\n").err().unwrap();
+    assert_eq!((err.line, err.column), (3, 1));
+}
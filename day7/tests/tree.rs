@@ -0,0 +1,31 @@
+use aoc_common::Solution;
+use day7::{tree, Day7, Entry};
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn tree_of_the_example() {
+    let root = Day7::parse(EXAMPLE).unwrap();
+    let expected = "\
+- / (dir, size=48381165)
+  - a (dir, size=94853)
+    - e (dir, size=584)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir, size=24933642)
+    - j (file, size=4060174)
+    - d.log (file, size=8033020)
+    - d.ext (file, size=5626152)
+    - k (file, size=7214296)
+";
+    assert_eq!(tree(&root), expected);
+}
+
+#[test]
+fn tree_of_a_single_file() {
+    assert_eq!(tree(&Entry::File(7)), "- / (file, size=7)\n");
+}
@@ -0,0 +1,129 @@
+use aoc_common::grid::Grid;
+use aoc_common::parse;
+use aoc_common::random::Rng;
+use aoc_common::{ParseError, Solution};
+use std::cmp;
+
+type Map = Grid<u8>;
+
+fn compute_visibility_horizontal(map: &Map, i: usize, visible: &mut Grid<bool>) {
+    let (mut lo, mut hi) = (0, map.width() - 1);
+    let (mut max_lo, mut max_hi) = (map[(i, lo)], map[(i, hi)]);
+
+    visible[(i, lo)] = true;
+    visible[(i, hi)] = true;
+
+    while lo < hi {
+        if map[(i, lo)] <= map[(i, hi)] {
+            lo += 1;
+            if map[(i, lo)] > max_lo {
+                max_lo = map[(i, lo)];
+                visible[(i, lo)] = true;
+            }
+        } else {
+            hi -= 1;
+            if map[(i, hi)] > max_hi {
+                max_hi = map[(i, hi)];
+                visible[(i, hi)] = true;
+            }
+        }
+    }
+}
+
+fn compute_visibility_vertical(map: &Map, j: usize, visible: &mut Grid<bool>) {
+    let (mut lo, mut hi) = (0, map.height() - 1);
+    let (mut max_lo, mut max_hi) = (map[(lo, j)], map[(hi, j)]);
+
+    visible[(lo, j)] = true;
+    visible[(hi, j)] = true;
+
+    while lo < hi {
+        if map[(lo, j)] <= map[(hi, j)] {
+            lo += 1;
+            if map[(lo, j)] > max_lo {
+                max_lo = map[(lo, j)];
+                visible[(lo, j)] = true;
+            }
+        } else {
+            hi -= 1;
+            if map[(hi, j)] > max_hi {
+                max_hi = map[(hi, j)];
+                visible[(hi, j)] = true;
+            }
+        }
+    }
+}
+
+fn compute_score<'a>(mut iter: impl Iterator<Item = &'a u8>) -> i32 {
+    let start = iter.next().unwrap();
+    let mut score = 0;
+
+    for next in iter {
+        score += 1;
+
+        if next >= start {
+            break;
+        }
+    }
+
+    score
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(input, "a tree height", |c| c.to_digit(10).map(|d| d as u8))?;
+
+        // the visibility sweeps walk rows and columns with the same index
+        if map.height() != map.width() {
+            let expected = format!("{} rows, as many as there are columns", map.width());
+            return Err(parse::missing(input, expected));
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> String {
+        let n = map.height();
+
+        let mut visible = Grid::new(n, n, false);
+        for i in 0..n {
+            compute_visibility_horizontal(map, i, &mut visible);
+            compute_visibility_vertical(map, i, &mut visible);
+        }
+
+        visible.cells().filter(|b| **b).count().to_string()
+    }
+
+    fn part2(map: &Self::Input) -> Option<String> {
+        let n = map.height();
+
+        let mut best_score = 0;
+        for i in 1..n - 1 {
+            for j in 1..n - 1 {
+                let mut score = 1;
+
+                score *= compute_score(map.column(j).take(i + 1).rev());
+                score *= compute_score(map.column(j).skip(i));
+
+                score *= compute_score(map.row(i)[..j + 1].iter().rev());
+                score *= compute_score(map.row(i)[j..].iter());
+
+                best_score = cmp::max(best_score, score);
+            }
+        }
+
+        Some(best_score.to_string())
+    }
+
+    // a size by size forest
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        Grid::from_fn(size, size, |_| rng.char_in('0', '9')).to_string()
+    }
+}
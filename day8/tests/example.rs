@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day8::Day8;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day8::parse(EXAMPLE).unwrap();
+    assert_eq!(Day8::part1(&input), "21");
+}
+
+#[test]
+fn part2() {
+    let input = Day8::parse(EXAMPLE).unwrap();
+    assert_eq!(Day8::part2(&input).unwrap(), "8");
+}
@@ -0,0 +1,206 @@
+use std::collections::HashSet;
+use aoc_common::grid::Grid;
+use aoc_common::parse;
+use aoc_common::point::{Dir4, Point2};
+use aoc_common::random::Rng;
+use aoc_common::render::{Frame, Recorder, Rgb};
+use aoc_common::step::Simulation;
+use aoc_common::{ParseError, Solution};
+
+type Rope = Vec<Point2>;
+
+// the longest single move, as every step of it is simulated
+const MAX_MOVE: u32 = 1000;
+
+fn move_knots(dir: Dir4, rope: &mut Rope) {
+    rope[0] += dir.delta();
+
+    for i in 1..rope.len() {
+        if rope[i - 1].chebyshev(rope[i]) > 1 {
+            let step = (rope[i - 1] - rope[i]).signum();
+            rope[i] += step;
+        }
+    }
+}
+
+fn tail_visits(moves: &[(Dir4, u32)], knots: usize) -> usize {
+    let mut rope = vec![Point2::ZERO; knots];
+    let mut visited = HashSet::new();
+
+    for &(dir, count) in moves {
+        for _ in 0..count {
+            move_knots(dir, &mut rope);
+            visited.insert(*rope.last().unwrap());
+        }
+    }
+
+    visited.len()
+}
+
+// the smallest box around every place the head goes, which the rest of the
+// rope never leaves as every knot only moves towards the one before it
+fn bounds(moves: &[(Dir4, u32)]) -> (Point2, Point2) {
+    let (mut head, mut lo, mut hi) = (Point2::ZERO, Point2::ZERO, Point2::ZERO);
+    for &(dir, count) in moves {
+        head += dir.delta() * count as i64;
+        lo = Point2::new(lo.x.min(head.x), lo.y.min(head.y));
+        hi = Point2::new(hi.x.max(head.x), hi.y.max(head.y));
+    }
+    (lo, hi)
+}
+
+// the knots, brighter towards the head, over where the tail has been
+fn frame((lo, hi): (Point2, Point2), rope: &Rope, visited: &HashSet<Point2>) -> Frame {
+    let (width, height) = ((hi.x - lo.x + 1) as usize, (hi.y - lo.y + 1) as usize);
+
+    let mut frame = Grid::from_fn(width, height, |(i, j)| {
+        let p = lo + Point2::new(j as i64, i as i64);
+        if visited.contains(&p) {
+            Rgb(70, 60, 110)
+        } else {
+            Rgb(20, 20, 30)
+        }
+    });
+
+    for (k, knot) in rope.iter().enumerate().rev() {
+        let shade = (255 - 150 * k / rope.len()) as u8;
+        let p = *knot - lo;
+        frame[(p.y as usize, p.x as usize)] = Rgb(shade, shade / 2, 40);
+    }
+
+    frame
+}
+
+// the rope moving one step of the head at a time
+struct Walk<'a> {
+    moves: &'a [(Dir4, u32)],
+    rope: Rope,
+    visited: HashSet<Point2>,
+    // the move being made, and how many of its steps are done
+    at: usize,
+    done: u32,
+    steps: i64,
+}
+
+impl Simulation for Walk<'_> {
+    fn step(&mut self) -> bool {
+        while self.at < self.moves.len() && self.done == self.moves[self.at].1 {
+            (self.at, self.done) = (self.at + 1, 0);
+        }
+        let Some(&(dir, _)) = self.moves.get(self.at) else {
+            return false;
+        };
+
+        move_knots(dir, &mut self.rope);
+        self.visited.insert(*self.rope.last().unwrap());
+        self.done += 1;
+        self.steps += 1;
+        true
+    }
+
+    fn counters(&self) -> Vec<(String, i64)> {
+        let head = self.rope[0];
+        [
+            ("step", self.steps),
+            ("move", self.at as i64 + 1),
+            ("visited", self.visited.len() as i64),
+            ("x", head.x),
+            ("y", head.y),
+        ]
+        .map(|(name, x)| (name.to_string(), x))
+        .to_vec()
+    }
+
+    // like the puzzle does, H for the head, then the knots by number and # where the tail went
+    fn render(&self) -> String {
+        let points = self.rope.iter().chain(&self.visited);
+        let lo = points.clone().copied().reduce(|a, b| Point2::new(a.x.min(b.x), a.y.min(b.y)));
+        let hi = points.copied().reduce(|a, b| Point2::new(a.x.max(b.x), a.y.max(b.y)));
+        let (lo, hi) = (lo.unwrap(), hi.unwrap());
+
+        let (width, height) = ((hi.x - lo.x + 1) as usize, (hi.y - lo.y + 1) as usize);
+        let mut map = Grid::from_fn(width, height, |(i, j)| {
+            let p = lo + Point2::new(j as i64, i as i64);
+            if self.visited.contains(&p) {
+                '#'
+            } else {
+                '.'
+            }
+        });
+        for (k, knot) in self.rope.iter().enumerate().rev() {
+            let p = *knot - lo;
+            map[(p.y as usize, p.x as usize)] = match k {
+                0 => 'H',
+                _ if self.rope.len() == 2 => 'T',
+                _ => char::from_digit(k as u32, 10).unwrap(),
+            };
+        }
+        map.to_string()
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<(Dir4, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, |s| {
+            let dir = match s.choice(&["U", "D", "R", "L"])? {
+                "U" => Dir4::Up,
+                "D" => Dir4::Down,
+                "R" => Dir4::Right,
+                _ => Dir4::Left,
+            };
+            s.expect(" ")?;
+            let count = s.number_in(0, MAX_MOVE)?;
+            Ok((dir, count))
+        })
+    }
+
+    fn part1(moves: &Self::Input) -> String {
+        tail_visits(moves, 2).to_string()
+    }
+
+    fn part2(moves: &Self::Input) -> Option<String> {
+        Some(tail_visits(moves, 10).to_string())
+    }
+
+    // the 10 knots of part 2, a frame for every step of the head
+    fn visualize(moves: &Self::Input, rec: &mut Recorder) -> bool {
+        let bounds = bounds(moves);
+        let mut rope = vec![Point2::ZERO; 10];
+        let mut visited = HashSet::from([Point2::ZERO]);
+
+        rec.record(|| frame(bounds, &rope, &visited));
+        for &(dir, count) in moves {
+            for _ in 0..count {
+                move_knots(dir, &mut rope);
+                visited.insert(*rope.last().unwrap());
+                rec.record(|| frame(bounds, &rope, &visited));
+            }
+        }
+
+        true
+    }
+
+    fn simulate(moves: &Self::Input, part: u32) -> Option<Box<dyn Simulation + '_>> {
+        Some(Box::new(Walk {
+            moves,
+            rope: vec![Point2::ZERO; if part == 1 { 2 } else { 10 }],
+            visited: HashSet::from([Point2::ZERO]),
+            at: 0,
+            done: 0,
+            steps: 0,
+        }))
+    }
+
+    // size moves of the head
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{} {}\n", rng.pick(&["U", "D", "R", "L"]), rng.range(1, 20)))
+            .collect()
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day9::Day9;
+
+const EXAMPLE: &str = include_str!("../example");
+
+#[test]
+fn part1() {
+    let input = Day9::parse(EXAMPLE).unwrap();
+    assert_eq!(Day9::part1(&input), "13");
+}
+
+#[test]
+fn part2() {
+    let input = Day9::parse(EXAMPLE).unwrap();
+    assert_eq!(Day9::part2(&input).unwrap(), "1");
+}
+
+#[test]
+fn moves_too_long_to_simulate() {
+    let err = Day9::parse("R 4\nU 1001\n").err().unwrap();
+    assert_eq!((err.line, err.column), (2, 3));
+}
@@ -0,0 +1,51 @@
+#![no_main]
+
+use aoc_common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fn solve<S: Solution>(input: &str) {
+    if let Ok(input) = S::parse(input) {
+        S::part1(&input);
+        S::part2(&input);
+    }
+}
+
+const DAYS: [fn(&str); 25] = [
+    solve::<day1::Day1>,
+    solve::<day2::Day2>,
+    solve::<day3::Day3>,
+    solve::<day4::Day4>,
+    solve::<day5::Day5>,
+    solve::<day6::Day6>,
+    solve::<day7::Day7>,
+    solve::<day8::Day8>,
+    solve::<day9::Day9>,
+    solve::<day10::Day10>,
+    solve::<day11::Day11>,
+    solve::<day12::Day12>,
+    solve::<day13::Day13>,
+    solve::<day14::Day14>,
+    solve::<day15::Day15>,
+    solve::<day16::Day16>,
+    solve::<day17::Day17>,
+    solve::<day18::Day18>,
+    solve::<day19::Day19>,
+    solve::<day20::Day20>,
+    solve::<day21::Day21>,
+    solve::<day22::Day22>,
+    solve::<day23::Day23>,
+    solve::<day24::Day24>,
+    solve::<day25::Day25>,
+];
+
+// the first byte picks the day and the rest is its input, which may be
+// anything at all but has to give an error rather than a panic, and be solved
+// without one if it parses
+fuzz_target!(|data: &[u8]| {
+    let Some((&day, input)) = data.split_first() else {
+        return;
+    };
+    if let Ok(input) = std::str::from_utf8(input) {
+        DAYS[day as usize % DAYS.len()](input);
+    }
+});